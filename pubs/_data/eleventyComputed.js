@@ -1,49 +1,33 @@
-import fs from 'fs/promises';
-import path from 'node:path';
-
 function injectWriteUpUrl(day, posts) {
     return posts[day] ? {'Write Up': posts[day]} : {};
 }
 
-async function buildDay(file, day, posts) {
-    const contents = await fs.readFile(file, 'utf-8')
-    const line = contents.split(/[\n\r]+/)[0]
-    const [,title, puzzleURL] =
-      line.match(/\[Advent of Code - Day \d+: _([^_]+)_]\(([^)]+)\)/) ?? []
+// The manifest is exported by the Rust binary with `cargo run -- manifest`
+function buildDay(entry, posts) {
+    const {day, title, puzzle_url, source_path, doc_path, test_count, timing_ms} = entry
 
     const links = {
-        Puzzle: puzzleURL,
+        Puzzle: puzzle_url,
         ...(injectWriteUpUrl(day, posts)),
-        Documentation: `/advent_of_code_2025/day_${day}/index.html`,
-        Source: `https://github.com/kamioftea/advent-of-code-2025/blob/main/src/day_${day}.rs`
+        Documentation: `/${doc_path}`,
+        Source: `https://github.com/kamioftea/advent-of-code-2025/blob/main/${source_path}`
     }
 
-    return {day, title, links};
-}
-
-async function buildSolutionData(posts) {
-    const solutions = [];
-    const dir = await fs.opendir(path.join('..', 'src'));
-    for await (const entry of dir) {
-        const matches = entry.name.match(/day_(\d+)\.rs/)
-        if(entry.isFile() && matches) {
-            solutions.push(await buildDay(path.join(dir.path, entry.name), parseInt(matches[1]), posts))
-        }
-    }
-    return solutions
+    return {day, title, links, testCount: test_count, timingMs: timing_ms};
 }
 
-
 // noinspection JSUnusedGlobalSymbols
 export default function () {
     // noinspection JSUnusedGlobalSymbols
     return {
-        solutions: async (data) => {
+        solutions: (data) => {
             const postsCollection = data.collections.post;
             const posts = Object.fromEntries(
                 [...(postsCollection ?? [])].map(post => [post.data.day, post.url])
             );
-            return [...(await buildSolutionData(posts))].sort((a, b) => a.day - b.day)
+            return (data.manifest ?? [])
+                .map(entry => buildDay(entry, posts))
+                .sort((a, b) => a.day - b.day)
         },
         title: data => data.title ||
             [
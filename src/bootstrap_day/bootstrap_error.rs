@@ -3,37 +3,37 @@ use std::fmt::Display;
 
 #[derive(Debug)]
 pub enum BootstrapError {
-    IoError(std::io::Error),
-    ReqwestError(reqwest::Error),
-    HtmlParseError(String),
+    Io(std::io::Error),
+    Reqwest(reqwest::Error),
+    HtmlParse(String),
 }
 
 impl From<reqwest::Error> for BootstrapError {
     fn from(err: reqwest::Error) -> Self {
-        BootstrapError::ReqwestError(err)
+        BootstrapError::Reqwest(err)
     }
 }
 
 impl From<std::io::Error> for BootstrapError {
     fn from(err: std::io::Error) -> Self {
-        BootstrapError::IoError(err)
+        BootstrapError::Io(err)
     }
 }
 
 impl From<String> for BootstrapError {
     fn from(err: String) -> Self {
-        BootstrapError::HtmlParseError(err)
+        BootstrapError::HtmlParse(err)
     }
 }
 
 impl Display for BootstrapError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            BootstrapError::IoError(e) => write!(f, "IO Error: {}", e),
-            BootstrapError::ReqwestError(e) => write!(f, "Reqwest Error: {}", e),
-            BootstrapError::HtmlParseError(e) => write!(f, "HTML Parse Error: {}", e),
+            BootstrapError::Io(e) => write!(f, "IO Error: {}", e),
+            BootstrapError::Reqwest(e) => write!(f, "Reqwest Error: {}", e),
+            BootstrapError::HtmlParse(e) => write!(f, "HTML Parse Error: {}", e),
         }
     }
 }
 
-impl Error for BootstrapError {}
\ No newline at end of file
+impl Error for BootstrapError {}
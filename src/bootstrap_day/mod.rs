@@ -1,5 +1,7 @@
+use crate::profiles::{self, DEFAULT_PROFILE};
 use itertools::Itertools;
 use regex::Regex;
+use reqwest::blocking::Client;
 use reqwest::cookie::Jar;
 use reqwest::Url;
 use scraper::{Html, Selector};
@@ -8,29 +10,42 @@ use std::fs::File;
 use std::io::copy;
 use std::sync::Arc;
 
-use bootstrap_error::BootstrapError;
+pub use bootstrap_error::BootstrapError;
 
 mod bootstrap_error;
 
-pub fn bootstrap_day(day: u8) -> Result<(), BootstrapError> {
-    let session_cookie =
-        fs::read_to_string("res/session_cookie.txt").expect("Failed to read session cookie");
+/// Build an HTTP client authenticated with the session cookie stored in `res/session_cookie.txt`, so that
+/// user-specific pages (inputs, private leaderboards, etc.) can be fetched from the Advent of Code site.
+pub fn build_client() -> Result<Client, BootstrapError> {
+    build_client_for_session("res/session_cookie.txt")
+}
+
+/// Build an HTTP client authenticated with the session cookie stored in the given file
+pub fn build_client_for_session(session_cookie_path: &str) -> Result<Client, BootstrapError> {
+    let session_cookie = fs::read_to_string(session_cookie_path)?;
 
-    let cookie = format!("session={}; Domain=adventofcode.com", session_cookie);
+    let cookie = format!("session={}; Domain=adventofcode.com", session_cookie.trim());
     let url = "https://www.adventofcode.com".parse::<Url>().unwrap();
 
     let jar = Jar::default();
     jar.add_cookie_str(cookie.as_str(), &url);
 
-    let client = reqwest::blocking::Client::builder()
+    let client = Client::builder()
         .cookie_store(true)
         .cookie_provider(Arc::new(jar))
         .build()?;
 
+    Ok(client)
+}
+
+pub fn bootstrap_day(day: u8) -> Result<(), BootstrapError> {
+    let client = build_client()?;
+
     let input_target = format!("https://www.adventofcode.com/2025/day/{}/input", day);
     let input_file_contents = client.get(input_target).send()?.text()?;
 
-    let output_filename = format!("res/day-{}-input.txt", day);
+    fs::create_dir_all(profiles::profile_dir(day))?;
+    let output_filename = profiles::input_path(day, DEFAULT_PROFILE);
     let mut output_file = File::create(output_filename.clone())?;
     copy(&mut input_file_contents.as_bytes(), &mut output_file)?;
 
@@ -66,12 +81,23 @@ pub fn bootstrap_day(day: u8) -> Result<(), BootstrapError> {
 
 use std::fs;
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-{day}-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-{day}/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day {day}.
-pub fn run() {{
-    let _contents = fs::read_to_string(\"res/day-{day}-input.txt\").expect(\"Failed to read file\");
+pub fn run(input_path: &str) {{
+    let contents = fs::read_to_string(input_path).expect(\"Failed to read file\");
+
+    let (part_one, part_two) = solve(&contents);
+
+    println!(\"Part one: {{}}\", part_one);
+    println!(\"Part two: {{}}\", part_two);
+}}
+
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(_contents: &str) -> (String, String) {{
+    (String::new(), String::new())
 }}
 
 #[cfg(test)]
@@ -85,6 +111,9 @@ mod tests {{
     copy(&mut rust_contents.as_bytes(), &mut rust_file)?;
 
     println!("Rust file written {}", rust_filename);
+    println!(
+        "Add `mod day_{day};` to main.rs and register the solution in registry.rs, then run `cargo run -- manifest`"
+    );
 
     let markdown_filename = format!("pubs/blog/day_{}.md", day);
     let markdown_contents = format!(
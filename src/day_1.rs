@@ -1,14 +1,24 @@
 //! This is my solution for [Advent of Code - Day 1: _Secret Entrance_](https://adventofcode.com/2025/day/1)
 //!
-//! * [`parse_input`] turns the input file into a list of [`Instruction`]s
+//! * [`read_instructions`] lazily parses [`Instruction`]s from the input, so they can be streamed from a file
+//! * [`Dial`] describes the size of the dial, where it starts, and the positions being counted. The puzzle's dial is
+//!   the default, 100 positions starting at 50 and counting 0.
 //! * [`turn_dial`] handles turning the dial for a single [`Instruction`], updating the position and
-//!   counting the number of times 0 is passed
-//! * [`count_zero_positions`] solves part one, tracking only the positions returned by [`parse_input`]
+//!   counting the number of times a target is passed
+//! * [`count_zero_positions`] solves part one, tracking only the positions returned by [`turn_dial`]
 //! * [`count_zero_passes`] solves part two, tracking both the count and the position
+//! * [`count_zeros`] solves both parts in a single pass, which [`run`] uses to stream the input
+//! * [`trace_dial`] follows the instructions one at a time, for the `dial-trace` command to show where the counts
+//!   change
+//! * [`Lock`] extends the puzzle to several dials, which [`count_lock_targets`] turns with instructions addressed to a
+//!   dial, e.g. `2:L30`, for the `lock` command
 
-use std::fs;
+use itertools::process_results;
+use std::borrow::Borrow;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
 enum Direction {
     /// Turn dial clockwise
     Right,
@@ -18,76 +28,311 @@ enum Direction {
 
 type Instruction = (Direction, u32);
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// A dial with `size` positions numbered from 0, and the positions to count the dial reaching
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Dial {
+    size: u32,
+    start: u32,
+    targets: Vec<u32>,
+}
+
+impl Dial {
+    /// Targets are deduplicated, so each one counts once when the dial reaches it
+    fn new(size: u32, start: u32, mut targets: Vec<u32>) -> Dial {
+        assert!(size > 0, "A dial needs at least one position");
+        assert!(
+            start < size && targets.iter().all(|&target| target < size),
+            "Positions must be on a dial of size {}",
+            size
+        );
+        targets.sort_unstable();
+        targets.dedup();
+
+        Dial {
+            size,
+            start,
+            targets,
+        }
+    }
+}
+
+impl Default for Dial {
+    /// The safe's dial from the puzzle
+    fn default() -> Self {
+        Dial::new(100, 50, vec![0])
+    }
+}
+
+/// The dial's movement for a single instruction, with the running totals after it
+#[derive(Debug, Eq, PartialEq)]
+struct TraceStep {
+    instruction: Instruction,
+    start: u32,
+    end: u32,
+    /// The number of times a target was reached during this instruction
+    target_count: u64,
+    total_stops: u64,
+    total_passes: u64,
+}
+
+/// An [`Instruction`] for one of the dials of a [`Lock`], numbered from 1
+type LockInstruction = (usize, Instruction);
+
+/// Several dials that turn independently, except that a dial can be coupled to the next one. The next dial then turns
+/// one click in the same direction each time the coupled dial wraps round past zero, like an odometer.
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Lock {
+    dials: Vec<Dial>,
+    /// Whether each dial carries into the next one
+    coupled: Vec<bool>,
+}
+
+impl Lock {
+    /// `coupled` lists the numbers of the dials that carry into the next one
+    fn new(dials: Vec<Dial>, coupled: &[usize]) -> Lock {
+        assert!(
+            coupled
+                .iter()
+                .all(|&number| number >= 1 && number < dials.len()),
+            "Only dials 1 to {} have a next dial to couple to",
+            dials.len().saturating_sub(1)
+        );
+        let coupled = (1..=dials.len())
+            .map(|number| coupled.contains(&number))
+            .collect();
+
+        Lock { dials, coupled }
+    }
+}
+
+/// Where one of the dials of a [`Lock`] finished, and how many times it stopped on and reached its targets
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+struct DialStats {
+    position: u32,
+    stops: u64,
+    passes: u64,
+}
+
+impl DialStats {
+    /// The stats for a dial that has not been turned yet
+    fn at_start(dial: &Dial) -> DialStats {
+        DialStats {
+            position: dial.start,
+            ..DialStats::default()
+        }
+    }
+}
+
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-1-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-1/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 1.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-1-input.txt").expect("Failed to read file");
-    let instructions = parse_input(contents);
+pub fn run(input_path: &str) {
+    let file = File::open(input_path).expect("Failed to read file");
+    let (zero_positions, zero_passes) =
+        process_results(read_instructions(BufReader::new(file)), |instructions| {
+            count_zeros(instructions)
+        })
+        .expect("Failed to read file");
 
-    println!(
-        "The dial stops on zero {} times",
-        count_zero_positions(&instructions)
-    );
+    println!("The dial stops on zero {} times", zero_positions);
 
-    println!(
-        "The dial passes zero {} times",
-        count_zero_passes(&instructions)
-    );
+    println!("The dial passes zero {} times", zero_passes);
+}
+
+/// The entry point for the `dial-trace [csv_path]` command, showing how the puzzle input moves the dial one instruction
+/// at a time. The trace is printed as a table, or written as CSV if a path is given.
+pub fn run_trace(input_path: &str, csv_path: Option<&str>) -> io::Result<()> {
+    let file = File::open(input_path)?;
+    let dial = Dial::default();
+    let instructions = read_instructions(BufReader::new(file));
+
+    match csv_path {
+        Some(path) => {
+            let mut output = BufWriter::new(File::create(path)?);
+            process_results(instructions, |instructions| {
+                write_trace_csv(&mut output, trace_dial(&dial, instructions))
+            })??;
+            output.flush()?;
+            println!("Trace saved to {}", path);
+        }
+        None => {
+            let mut output = BufWriter::new(io::stdout().lock());
+            process_results(instructions, |instructions| {
+                write_trace_table(&mut output, trace_dial(&dial, instructions))
+            })??;
+            output.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The entry point for the `lock <path> [--odometer]` command, following the instructions in the file with a lock of
+/// puzzle dials, one for each dial number used. With `odometer` set each dial is coupled to the next.
+pub fn run_lock(path: &str, odometer: bool) -> io::Result<()> {
+    let stats = follow_lock_instructions(|| File::open(path).map(BufReader::new), odometer)?;
+
+    println!("Dial  Position  Stops  Passes");
+    for (index, stats) in stats.iter().enumerate() {
+        println!(
+            "{:>4}  {:>8}  {:>5}  {:>6}",
+            index + 1,
+            stats.position,
+            stats.stops,
+            stats.passes
+        );
+    }
+
+    Ok(())
+}
+
+/// Read the lock instructions twice, first to find the highest dial number, then to turn the dials. The lock then has
+/// every dial before any are turned, so a carry always has a dial to land on, without the instructions all being held
+/// in memory.
+fn follow_lock_instructions<R: BufRead>(
+    open: impl Fn() -> io::Result<R>,
+    odometer: bool,
+) -> io::Result<Vec<DialStats>> {
+    let dial_count = process_results(read_lock_instructions(open()?), |instructions| {
+        instructions.map(|(number, _)| number).max().unwrap_or(1)
+    })?;
+    let coupled: Vec<usize> = if odometer {
+        (1..dial_count).collect()
+    } else {
+        Vec::new()
+    };
+    let lock = Lock::new(vec![Dial::default(); dial_count], &coupled);
+
+    process_results(read_lock_instructions(open()?), |instructions| {
+        count_lock_targets(&lock, instructions)
+    })
+}
+
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    let instructions: Vec<Instruction> = read_instructions(contents.as_bytes())
+        .collect::<io::Result<_>>()
+        .expect("The input is already in memory, so can be read");
+
+    (
+        count_zero_positions(&instructions).to_string(),
+        count_zero_passes(&instructions).to_string(),
+    )
+}
+
+/// Parse an instruction from each line as it is read, so only one line of the input needs to be in memory at a time
+fn read_instructions(reader: impl BufRead) -> impl Iterator<Item = io::Result<Instruction>> {
+    non_blank_lines(reader).map(|line| line.map(|line| parse_instruction(&line)))
+}
+
+/// The lines of the input as they are read, skipping blank lines such as a trailing one. A failure to read is returned
+/// in place of the line, for the caller to stop at.
+fn non_blank_lines(reader: impl BufRead) -> impl Iterator<Item = io::Result<String>> {
+    reader
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
 }
 
 /// Lines start `L` or `R` indicating the direction, then have a number indicating how far to turn
 /// the dial in that direction
-fn parse_input(input: String) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(|line| {
-            let (direction, distance_str) = line.split_at(1);
-            let distance = distance_str.parse::<u32>().unwrap();
-
-            match direction {
-                "R" => (Direction::Right, distance),
-                "L" => (Direction::Left, distance),
-                _ => unreachable!("Direction is always L or R"),
-            }
-        })
-        .collect()
+fn parse_instruction(line: &str) -> Instruction {
+    let (direction, distance_str) = line.split_at(1);
+    let distance = distance_str.parse::<u32>().unwrap();
+
+    match direction {
+        "R" => (Direction::Right, distance),
+        "L" => (Direction::Left, distance),
+        _ => unreachable!("Direction is always L or R"),
+    }
+}
+
+/// As [`read_instructions`], but lines can start with the number of the dial to turn followed by a colon, e.g.
+/// `2:L30`. Lines without a dial number turn dial 1.
+fn read_lock_instructions(
+    reader: impl BufRead,
+) -> impl Iterator<Item = io::Result<LockInstruction>> {
+    non_blank_lines(reader).map(|line| line.map(|line| parse_lock_instruction(&line)))
+}
+
+fn parse_lock_instruction(line: &str) -> LockInstruction {
+    match line.split_once(':') {
+        Some((number, instruction)) => {
+            let number = number.parse::<usize>().unwrap();
+            assert!(number >= 1, "Dials are numbered from 1");
+
+            (number, parse_instruction(instruction))
+        }
+        None => (1, parse_instruction(line)),
+    }
 }
 
 /// Given a starting position return
-/// - How many times the dial reached zero during the turn (excluding if it started on zero, which is already counted
-///   at the end of the previous instruction.
+/// - How many times the dial reached a target during the turn (excluding if it started on one, which is already
+///   counted at the end of the previous instruction).
 /// - The new position
-fn turn_dial(position: u32, (direction, distance): &Instruction) -> (u32, u32) {
-    let full_rotations = distance / 100;
-    let remaining_distance = distance % 100;
+///
+/// The first click to reach a target is how far it is away in the direction of the turn, or a full turn if the dial
+/// is already on it. After that it is reached again every full turn.
+///
+/// The clicks are counted in `u64`, so that neither a dial with nearly `u32::MAX` positions nor a turn of nearly
+/// `u32::MAX` clicks can overflow.
+fn turn_dial(dial: &Dial, position: u32, (direction, distance): &Instruction) -> (u64, u32) {
+    let (size, position, distance) = (
+        u64::from(dial.size),
+        u64::from(position),
+        u64::from(*distance),
+    );
+    let clicks_to = |target: u32| match direction {
+        Direction::Right => (u64::from(target) + size - position) % size,
+        Direction::Left => (position + size - u64::from(target)) % size,
+    };
+
+    let target_count = dial
+        .targets
+        .iter()
+        .map(|&target| match clicks_to(target) {
+            0 => size,
+            clicks => clicks,
+        })
+        .filter(|&first_click| first_click <= distance)
+        .map(|first_click| (distance - first_click) / size + 1)
+        .sum();
 
-    let delta = match direction {
-        Direction::Right => remaining_distance,
-        Direction::Left => 100 - remaining_distance,
+    let remaining_distance = distance % size;
+    let new_position = match direction {
+        Direction::Right => (position + remaining_distance) % size,
+        Direction::Left => (position + size - remaining_distance) % size,
     };
-    let new_position = position + delta;
 
-    let passes_zero_again = position > 0
-        && match direction {
-            Direction::Right => new_position >= 100,
-            Direction::Left => new_position <= 100,
-        };
+    // The position is less than the size of the dial, so fits back in a u32
+    (target_count, new_position as u32)
+}
 
-    (
-        full_rotations + if passes_zero_again { 1 } else { 0 },
-        new_position % 100,
-    )
+/// The number of times the dial wraps round past zero during the turn, i.e. clicks from the last position to 0 when
+/// turning right, or from 0 to the last position when turning left
+fn count_wraps(dial: &Dial, position: u32, (direction, distance): &Instruction) -> u32 {
+    match direction {
+        // The sum can overflow a u32, but there can't be more wraps than clicks
+        Direction::Right => {
+            ((u64::from(position) + u64::from(*distance)) / u64::from(dial.size)) as u32
+        }
+        Direction::Left if *distance > position => (distance - position - 1) / dial.size + 1,
+        Direction::Left => 0,
+    }
 }
 
-/// Solves part 1, find the position after each instruction, counting each time it is zero
-fn count_zero_positions(instructions: &Vec<Instruction>) -> u32 {
-    let mut position = 50;
+/// Find the position after each instruction, counting each time it is a target
+fn count_target_positions(
+    dial: &Dial,
+    instructions: impl IntoIterator<Item = impl Borrow<Instruction>>,
+) -> u64 {
+    let mut position = dial.start;
     let mut count = 0;
     for instruction in instructions {
-        (_, position) = turn_dial(position, instruction);
-        if position == 0 {
+        (_, position) = turn_dial(dial, position, instruction.borrow());
+        if dial.targets.contains(&position) {
             count += 1
         }
     }
@@ -95,23 +340,185 @@ fn count_zero_positions(instructions: &Vec<Instruction>) -> u32 {
     count
 }
 
-/// Solves part 2, find the number of times the dial reaches zero whilst carrying out the instructions
-fn count_zero_passes(instructions: &Vec<Instruction>) -> u32 {
-    let mut position = 50;
+/// Find the number of times the dial reaches a target whilst carrying out the instructions
+fn count_target_passes(
+    dial: &Dial,
+    instructions: impl IntoIterator<Item = impl Borrow<Instruction>>,
+) -> u64 {
+    let mut position = dial.start;
     let mut count = 0;
     for instruction in instructions {
-        let (zero_count, new_pos) = turn_dial(position, instruction);
-        count += zero_count;
+        let (target_count, new_pos) = turn_dial(dial, position, instruction.borrow());
+        count += target_count;
         position = new_pos;
     }
 
     count
 }
 
+/// Solves part 1, find the position after each instruction, counting each time it is zero
+fn count_zero_positions(instructions: impl IntoIterator<Item = impl Borrow<Instruction>>) -> u64 {
+    count_target_positions(&Dial::default(), instructions)
+}
+
+/// Solves part 2, find the number of times the dial reaches zero whilst carrying out the instructions
+fn count_zero_passes(instructions: impl IntoIterator<Item = impl Borrow<Instruction>>) -> u64 {
+    count_target_passes(&Dial::default(), instructions)
+}
+
+/// Follow the instructions, yielding the dial's movement for each one along with the running totals of
+/// [`count_target_positions`] and [`count_target_passes`]
+fn trace_dial(
+    dial: &Dial,
+    instructions: impl IntoIterator<Item = impl Borrow<Instruction>>,
+) -> impl Iterator<Item = TraceStep> {
+    instructions.into_iter().scan(
+        (dial.start, 0, 0),
+        |(position, total_stops, total_passes), instruction| {
+            let instruction = *instruction.borrow();
+            let start = *position;
+            let (target_count, end) = turn_dial(dial, start, &instruction);
+
+            *position = end;
+            *total_passes += target_count;
+            if dial.targets.contains(&end) {
+                *total_stops += 1;
+            }
+
+            Some(TraceStep {
+                instruction,
+                start,
+                end,
+                target_count,
+                total_stops: *total_stops,
+                total_passes: *total_passes,
+            })
+        },
+    )
+}
+
+/// Follow the instructions for a lock, returning the stats for each dial. Each time a dial wraps round and is coupled
+/// to the next, the next dial is turned by the number of wraps. A stop is counted for every dial the instruction turns
+/// that ends on a target, so a carry can count for more than one dial.
+fn count_lock_targets(
+    lock: &Lock,
+    instructions: impl IntoIterator<Item = impl Borrow<LockInstruction>>,
+) -> Vec<DialStats> {
+    let mut stats: Vec<DialStats> = lock.dials.iter().map(DialStats::at_start).collect();
+
+    for instruction in instructions {
+        turn_lock(lock, &mut stats, instruction.borrow());
+    }
+
+    stats
+}
+
+/// Carry out a single instruction for [`count_lock_targets`], updating the stats of each dial it turns
+fn turn_lock(
+    lock: &Lock,
+    stats: &mut [DialStats],
+    &(number, (direction, mut distance)): &LockInstruction,
+) {
+    assert!(
+        number <= lock.dials.len(),
+        "There is no dial {} on a lock with {} dials",
+        number,
+        lock.dials.len()
+    );
+
+    let mut index = number - 1;
+    loop {
+        let dial = &lock.dials[index];
+        let (target_count, new_position) =
+            turn_dial(dial, stats[index].position, &(direction, distance));
+        let wraps = count_wraps(dial, stats[index].position, &(direction, distance));
+
+        stats[index].position = new_position;
+        stats[index].passes += target_count;
+        if dial.targets.contains(&new_position) {
+            stats[index].stops += 1;
+        }
+
+        if !lock.coupled[index] || wraps == 0 {
+            break;
+        }
+        index += 1;
+        distance = wraps;
+    }
+}
+
+/// Solves both parts in a single pass over the instructions, returning the zero positions and passes
+fn count_zeros(instructions: impl IntoIterator<Item = impl Borrow<Instruction>>) -> (u64, u64) {
+    trace_dial(&Dial::default(), instructions)
+        .last()
+        .map_or((0, 0), |step| (step.total_stops, step.total_passes))
+}
+
+/// Format an instruction as it appears in the puzzle input, e.g. `L68`
+fn format_instruction((direction, distance): &Instruction) -> String {
+    match direction {
+        Direction::Right => format!("R{}", distance),
+        Direction::Left => format!("L{}", distance),
+    }
+}
+
+/// Write each step as a row of a table as it is produced, so the steps don't all need to be held in memory
+fn write_trace_table(
+    output: &mut impl Write,
+    steps: impl IntoIterator<Item = TraceStep>,
+) -> io::Result<()> {
+    writeln!(
+        output,
+        "{:>5}  {:<11}  {:>5}  {:>5}  {:>4}  {:>5}  {:>6}",
+        "Step", "Instruction", "Start", "End", "Hits", "Stops", "Passes"
+    )?;
+
+    for (index, step) in steps.into_iter().enumerate() {
+        writeln!(
+            output,
+            "{:>5}  {:<11}  {:>5}  {:>5}  {:>4}  {:>5}  {:>6}",
+            index + 1,
+            format_instruction(&step.instruction),
+            step.start,
+            step.end,
+            step.target_count,
+            step.total_stops,
+            step.total_passes
+        )?;
+    }
+
+    Ok(())
+}
+
+/// As [`write_trace_table`], but writing each step as a CSV row
+fn write_trace_csv(
+    output: &mut impl Write,
+    steps: impl IntoIterator<Item = TraceStep>,
+) -> io::Result<()> {
+    writeln!(output, "step,instruction,start,end,hits,stops,passes")?;
+
+    for (index, step) in steps.into_iter().enumerate() {
+        writeln!(
+            output,
+            "{},{},{},{},{},{},{}",
+            index + 1,
+            format_instruction(&step.instruction),
+            step.start,
+            step.end,
+            step.target_count,
+            step.total_stops,
+            step.total_passes
+        )?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::day_1::Direction::{Left, Right};
     use crate::day_1::*;
+    use proptest::prelude::*;
 
     fn sample_input() -> String {
         "L68
@@ -143,35 +550,553 @@ L82"
     }
 
     #[test]
-    fn can_parse_input() {
-        assert_eq!(parse_input(sample_input()), sample_instructions());
+    fn can_read_instructions() {
+        assert_eq!(
+            read_instructions(sample_input().as_bytes())
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap(),
+            sample_instructions()
+        );
+
+        // Lines are split across the reader's buffer, and can have Windows line endings
+        let input = sample_input().replace('\n', "\r\n");
+        assert_eq!(
+            read_instructions(BufReader::with_capacity(4, input.as_bytes()))
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap(),
+            sample_instructions()
+        );
+    }
+
+    #[test]
+    fn can_skip_blank_lines() {
+        assert_eq!(
+            read_instructions("L68\n\nR48\n\n".as_bytes())
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap(),
+            vec![(Direction::Left, 68), (Direction::Right, 48)]
+        );
+    }
+
+    #[test]
+    fn can_return_read_errors() {
+        assert!(
+            read_instructions(&b"L68\n\xffL30\n"[..])
+                .collect::<io::Result<Vec<_>>>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn can_create_dial() {
+        assert_eq!(
+            Dial::default(),
+            Dial {
+                size: 100,
+                start: 50,
+                targets: vec![0]
+            }
+        );
+        assert_eq!(Dial::new(10, 3, vec![7, 2, 7]).targets, vec![2, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Positions must be on a dial of size 10")]
+    fn cannot_create_dial_with_target_off_dial() {
+        Dial::new(10, 3, vec![10]);
     }
 
     #[test]
     fn can_turn_dial() {
-        assert_eq!(turn_dial(11, &(Right, 8)).1, 19);
-        assert_eq!(turn_dial(19, &(Left, 19)).1, 0);
+        let dial = Dial::default();
 
-        assert_eq!(turn_dial(5, &(Left, 10)).1, 95);
-        assert_eq!(turn_dial(95, &(Right, 5)).1, 0);
+        assert_eq!(turn_dial(&dial, 11, &(Right, 8)).1, 19);
+        assert_eq!(turn_dial(&dial, 19, &(Left, 19)).1, 0);
 
-        assert_eq!(turn_dial(50, &(Right, 949)), (9, 99));
-        assert_eq!(turn_dial(50, &(Right, 950)), (10, 0));
+        assert_eq!(turn_dial(&dial, 5, &(Left, 10)).1, 95);
+        assert_eq!(turn_dial(&dial, 95, &(Right, 5)).1, 0);
 
-        assert_eq!(turn_dial(50, &(Left, 949)), (9, 1));
-        assert_eq!(turn_dial(50, &(Left, 950)), (10, 0));
+        assert_eq!(turn_dial(&dial, 50, &(Right, 949)), (9, 99));
+        assert_eq!(turn_dial(&dial, 50, &(Right, 950)), (10, 0));
 
-        assert_eq!(turn_dial(0, &(Right, 10)), (0, 10));
-        assert_eq!(turn_dial(0, &(Left, 10)), (0, 90));
+        assert_eq!(turn_dial(&dial, 50, &(Left, 949)), (9, 1));
+        assert_eq!(turn_dial(&dial, 50, &(Left, 950)), (10, 0));
+
+        assert_eq!(turn_dial(&dial, 0, &(Right, 10)), (0, 10));
+        assert_eq!(turn_dial(&dial, 0, &(Left, 10)), (0, 90));
+    }
+
+    #[test]
+    fn can_turn_smaller_dial_with_several_targets() {
+        let dial = Dial::new(10, 0, vec![3, 7]);
+
+        assert_eq!(turn_dial(&dial, 0, &(Right, 3)), (1, 3));
+        assert_eq!(turn_dial(&dial, 3, &(Right, 20)), (4, 3));
+        assert_eq!(turn_dial(&dial, 3, &(Left, 5)), (0, 8));
+        assert_eq!(turn_dial(&dial, 8, &(Left, 11)), (3, 7));
+    }
+
+    #[test]
+    fn can_count_targets_on_other_dials() {
+        let dial = Dial::new(10, 5, vec![0, 5]);
+        let instructions = [(Right, 5), (Left, 15), (Right, 2)];
+
+        assert_eq!(count_target_positions(&dial, instructions), 2);
+        assert_eq!(count_target_passes(&dial, instructions), 4);
+    }
+
+    #[test]
+    fn can_count_past_u32_max() {
+        let dial = Dial::new(1, 0, vec![0]);
+        let instructions = [(Right, u32::MAX), (Left, u32::MAX)];
+
+        assert_eq!(
+            count_target_passes(&dial, instructions),
+            2 * u64::from(u32::MAX)
+        );
+
+        // A full turn of the largest dial, and wrapping from the last position, would overflow adding in a u32
+        assert_eq!(
+            turn_dial(
+                &Dial::new(u32::MAX, u32::MAX - 1, vec![0]),
+                u32::MAX - 1,
+                &(Right, u32::MAX)
+            ),
+            (1, u32::MAX - 1)
+        );
+        assert_eq!(
+            count_wraps(&Dial::new(10, 0, vec![0]), 9, &(Right, u32::MAX)),
+            429496730
+        );
     }
 
     #[test]
     fn can_count_zero_positions() {
-        assert_eq!(count_zero_positions(&sample_instructions()), 3)
+        assert_eq!(count_zero_positions(sample_instructions()), 3)
     }
 
     #[test]
     fn can_count_zero_passes() {
-        assert_eq!(count_zero_passes(&sample_instructions()), 6)
+        assert_eq!(count_zero_passes(sample_instructions()), 6)
+    }
+
+    #[test]
+    fn can_count_zeros_in_one_pass() {
+        assert_eq!(
+            process_results(
+                read_instructions(sample_input().as_bytes()),
+                |instructions| { count_zeros(instructions) }
+            )
+            .unwrap(),
+            (3, 6)
+        );
+        assert_eq!(count_zeros(Vec::<Instruction>::new()), (0, 0));
+    }
+
+    #[test]
+    fn can_trace_dial() {
+        let steps: Vec<TraceStep> = trace_dial(&Dial::default(), &sample_instructions()).collect();
+
+        assert_eq!(steps.len(), 10);
+        assert_eq!(
+            steps[0],
+            TraceStep {
+                instruction: (Left, 68),
+                start: 50,
+                end: 82,
+                target_count: 1,
+                total_stops: 0,
+                total_passes: 1
+            }
+        );
+        assert_eq!(
+            steps[9],
+            TraceStep {
+                instruction: (Left, 82),
+                start: 14,
+                end: 32,
+                target_count: 1,
+                total_stops: 3,
+                total_passes: 6
+            }
+        );
+    }
+
+    #[test]
+    fn can_write_trace() {
+        let (dial, instructions) = (Dial::default(), sample_instructions());
+        let steps = || trace_dial(&dial, &instructions[..3]);
+
+        let mut table = Vec::new();
+        write_trace_table(&mut table, steps()).unwrap();
+        assert_eq!(
+            String::from_utf8(table).unwrap(),
+            " Step  Instruction  Start    End  Hits  Stops  Passes
+    1  L68             50     82     1      0       1
+    2  L30             82     52     0      0       1
+    3  R48             52      0     1      1       2
+"
+        );
+        let mut csv = Vec::new();
+        write_trace_csv(&mut csv, steps()).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "step,instruction,start,end,hits,stops,passes
+1,L68,50,82,1,0,1
+2,L30,82,52,0,0,1
+3,R48,52,0,1,1,2
+"
+        );
+    }
+
+    #[test]
+    fn can_parse_lock_instructions() {
+        assert_eq!(
+            read_lock_instructions("L68\n2:R48\n\n12:L5\n".as_bytes())
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap(),
+            vec![(1, (Left, 68)), (2, (Right, 48)), (12, (Left, 5))]
+        );
+    }
+
+    #[test]
+    fn can_count_wraps() {
+        let dial = Dial::new(10, 0, vec![0]);
+
+        assert_eq!(count_wraps(&dial, 5, &(Right, 4)), 0);
+        assert_eq!(count_wraps(&dial, 5, &(Right, 5)), 1);
+        assert_eq!(count_wraps(&dial, 5, &(Right, 25)), 3);
+        assert_eq!(count_wraps(&dial, 5, &(Left, 5)), 0);
+        assert_eq!(count_wraps(&dial, 5, &(Left, 6)), 1);
+        assert_eq!(count_wraps(&dial, 0, &(Left, 1)), 1);
+        assert_eq!(count_wraps(&dial, 0, &(Left, 21)), 3);
+    }
+
+    #[test]
+    fn can_count_single_dial_lock() {
+        let lock = Lock::new(vec![Dial::default()], &[]);
+        let instructions: Vec<LockInstruction> = sample_instructions()
+            .into_iter()
+            .map(|instruction| (1, instruction))
+            .collect();
+
+        assert_eq!(
+            count_lock_targets(&lock, instructions),
+            vec![DialStats {
+                position: 32,
+                stops: 3,
+                passes: 6
+            }]
+        );
+    }
+
+    #[test]
+    fn can_count_independent_dials() {
+        let lock = Lock::new(vec![Dial::default(), Dial::new(10, 0, vec![0])], &[]);
+        let instructions = [(2, (Right, 10)), (1, (Left, 150)), (2, (Left, 3))];
+
+        assert_eq!(
+            count_lock_targets(&lock, instructions),
+            vec![
+                DialStats {
+                    position: 0,
+                    stops: 1,
+                    passes: 2
+                },
+                DialStats {
+                    position: 7,
+                    stops: 1,
+                    passes: 1
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn can_carry_like_an_odometer() {
+        let lock = Lock::new(vec![Dial::new(10, 0, vec![0]); 3], &[1, 2]);
+        let instructions = [
+            (1, (Right, 25)),
+            (1, (Left, 6)),
+            (1, (Right, 1)),
+            (2, (Left, 2)),
+        ];
+
+        // Reading the dials in reverse: 025, 019, then 020 as dial 1 carries, and 000 without a borrow from dial 3
+        assert_eq!(
+            count_lock_targets(&lock, instructions),
+            vec![
+                DialStats {
+                    position: 0,
+                    stops: 1,
+                    passes: 4
+                },
+                DialStats {
+                    position: 0,
+                    stops: 1,
+                    passes: 1
+                },
+                DialStats {
+                    position: 0,
+                    stops: 0,
+                    passes: 0
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn can_carry_into_dial_not_yet_mentioned() {
+        // Dial 1 wraps past zero twice, at 100 and 200 clicks from the start, before dial 2 is named
+        let carry_first = follow_lock_instructions(|| Ok("1:R150\n2:R0\n".as_bytes()), true);
+        let carry_last = follow_lock_instructions(|| Ok("2:R0\n1:R150\n".as_bytes()), true);
+
+        let expected = vec![
+            DialStats {
+                position: 0,
+                stops: 1,
+                passes: 2,
+            },
+            DialStats {
+                position: 52,
+                stops: 0,
+                passes: 0,
+            },
+        ];
+        assert_eq!(carry_first.unwrap(), expected);
+        assert_eq!(carry_last.unwrap(), expected);
+
+        // Without the odometer, the lock is still sized for the highest dial, but nothing carries
+        assert_eq!(
+            follow_lock_instructions(|| Ok("1:R150\n2:R0\n".as_bytes()), false).unwrap()[1],
+            DialStats {
+                position: 50,
+                stops: 0,
+                passes: 0,
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "There is no dial 3 on a lock with 2 dials")]
+    fn cannot_turn_missing_dial() {
+        let lock = Lock::new(vec![Dial::default(); 2], &[1]);
+        count_lock_targets(&lock, [(3, (Left, 1))]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only dials 1 to 1 have a next dial to couple to")]
+    fn cannot_couple_last_dial() {
+        Lock::new(vec![Dial::default(); 2], &[2]);
+    }
+
+    /// The oracle for [`turn_dial`], moving the dial one click at a time and counting each time it lands on a target
+    fn click_dial(
+        dial: &Dial,
+        mut position: u32,
+        (direction, distance): &Instruction,
+    ) -> (u64, u32) {
+        let mut target_count = 0;
+        for _ in 0..*distance {
+            position = match direction {
+                Right => (position + 1) % dial.size,
+                Left => (position + dial.size - 1) % dial.size,
+            };
+            if dial.targets.contains(&position) {
+                target_count += 1;
+            }
+        }
+
+        (target_count, position)
+    }
+
+    /// The oracle for both counts, following the instructions with [`click_dial`]
+    fn click_counts(dial: &Dial, instructions: &[Instruction]) -> (u64, u64) {
+        let mut position = dial.start;
+        let mut target_positions = 0;
+        let mut target_passes = 0;
+        for instruction in instructions {
+            let (target_count, new_position) = click_dial(dial, position, instruction);
+            position = new_position;
+            target_passes += target_count;
+            if dial.targets.contains(&position) {
+                target_positions += 1;
+            }
+        }
+
+        (target_positions, target_passes)
+    }
+
+    /// The oracle for [`count_lock_targets`], clicking a dial one position at a time, and clicking the next dial as
+    /// soon as a coupled dial wraps
+    fn click_lock(lock: &Lock, instructions: &[LockInstruction]) -> Vec<DialStats> {
+        let mut positions: Vec<u32> = lock.dials.iter().map(|dial| dial.start).collect();
+        let mut stats = vec![DialStats::default(); lock.dials.len()];
+
+        for &(number, (direction, distance)) in instructions {
+            let mut turned = vec![false; lock.dials.len()];
+            for _ in 0..distance {
+                let mut index = number - 1;
+                loop {
+                    let size = lock.dials[index].size;
+                    let (position, wrapped) = match direction {
+                        Right => ((positions[index] + 1) % size, positions[index] == size - 1),
+                        Left => ((positions[index] + size - 1) % size, positions[index] == 0),
+                    };
+                    positions[index] = position;
+                    turned[index] = true;
+                    if lock.dials[index].targets.contains(&position) {
+                        stats[index].passes += 1;
+                    }
+
+                    if !wrapped || !lock.coupled[index] {
+                        break;
+                    }
+                    index += 1;
+                }
+            }
+
+            turned[number - 1] = true;
+            for index in 0..lock.dials.len() {
+                if turned[index] && lock.dials[index].targets.contains(&positions[index]) {
+                    stats[index].stops += 1;
+                }
+            }
+        }
+
+        for (stats, position) in stats.iter_mut().zip(positions) {
+            stats.position = position;
+        }
+
+        stats
+    }
+
+    fn lock() -> impl Strategy<Value = Lock> {
+        prop::collection::vec(dial(), 1..4).prop_flat_map(|dials| {
+            let dial_count = dials.len();
+            prop::collection::vec(any::<bool>(), dial_count - 1).prop_map(move |couplings| {
+                let coupled: Vec<usize> = (1..dial_count)
+                    .filter(|&number| couplings[number - 1])
+                    .collect();
+                Lock::new(dials.clone(), &coupled)
+            })
+        })
+    }
+
+    fn lock_instructions(dial_count: usize) -> impl Strategy<Value = Vec<LockInstruction>> {
+        prop::collection::vec((1..=dial_count, instruction()), 1..15)
+    }
+
+    fn dial() -> impl Strategy<Value = Dial> {
+        (1..30u32).prop_flat_map(|size| {
+            (0..size, prop::collection::vec(0..size, 0..4))
+                .prop_map(move |(start, targets)| Dial::new(size, start, targets))
+        })
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        (
+            prop_oneof![Just(Left), Just(Right)],
+            // Bias towards exact multiples of 100, which are easy to get wrong
+            prop_oneof![0..1000u32, (0..10u32).prop_map(|n| n * 100)],
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn turn_dial_matches_clicking(position in 0..100u32, instruction in instruction()) {
+            let dial = Dial::default();
+
+            prop_assert_eq!(
+                turn_dial(&dial, position, &instruction),
+                click_dial(&dial, position, &instruction)
+            );
+        }
+
+        #[test]
+        fn counts_match_clicking(instructions in prop::collection::vec(instruction(), 0..50)) {
+            let (zero_positions, zero_passes) = click_counts(&Dial::default(), &instructions);
+
+            prop_assert_eq!(count_zero_positions(&instructions), zero_positions);
+            prop_assert_eq!(count_zero_passes(&instructions), zero_passes);
+            prop_assert_eq!(count_zeros(&instructions), (zero_positions, zero_passes));
+        }
+
+        #[test]
+        fn locks_match_clicking(
+            (lock, instructions) in lock().prop_flat_map(|lock| {
+                let dial_count = lock.dials.len();
+                (Just(lock), lock_instructions(dial_count))
+            })
+        ) {
+            prop_assert_eq!(count_lock_targets(&lock, &instructions), click_lock(&lock, &instructions));
+        }
+
+        #[test]
+        fn odometer_reads_total_distance(
+            size in 2..10u32,
+            dial_count in 1..4usize,
+            instructions in lock_instructions(3),
+        ) {
+            let lock = Lock::new(
+                vec![Dial::new(size, 0, vec![0]); dial_count],
+                &(1..dial_count).collect::<Vec<_>>(),
+            );
+            let instructions: Vec<LockInstruction> = instructions
+                .into_iter()
+                .filter(|&(number, _)| number <= dial_count)
+                .collect();
+
+            let place_value = |number: usize| i64::from(size).pow(number as u32 - 1);
+            let expected = instructions
+                .iter()
+                .map(|&(number, (direction, distance))| match direction {
+                    Right => i64::from(distance) * place_value(number),
+                    Left => -i64::from(distance) * place_value(number),
+                })
+                .sum::<i64>()
+                .rem_euclid(place_value(dial_count + 1));
+            let reading: i64 = count_lock_targets(&lock, &instructions)
+                .iter()
+                .enumerate()
+                .map(|(index, stats)| i64::from(stats.position) * place_value(index + 1))
+                .sum();
+
+            prop_assert_eq!(reading, expected);
+        }
+
+        #[test]
+        fn trace_totals_match_counts(
+            dial in dial(),
+            instructions in prop::collection::vec(instruction(), 1..20),
+        ) {
+            let last_step = trace_dial(&dial, &instructions).last().unwrap();
+
+            prop_assert_eq!(
+                (last_step.total_stops, last_step.total_passes),
+                click_counts(&dial, &instructions)
+            );
+        }
+
+        #[test]
+        fn other_dials_match_clicking(
+            dial in dial(),
+            position_seed in any::<u32>(),
+            instructions in prop::collection::vec(instruction(), 1..20),
+        ) {
+            let position = position_seed % dial.size;
+            prop_assert_eq!(
+                turn_dial(&dial, position, &instructions[0]),
+                click_dial(&dial, position, &instructions[0])
+            );
+
+            prop_assert_eq!(
+                (
+                    count_target_positions(&dial, &instructions),
+                    count_target_passes(&dial, &instructions)
+                ),
+                click_counts(&dial, &instructions)
+            );
+        }
     }
 }
@@ -1,45 +1,411 @@
 //! This is my solution for [Advent of Code - Day 2: _Gift Shop_](https://adventofcode.com/2025/day/2)
 //!
-//! - [`parse_input`] splits the range list and parses each to a pair of `u64`s
+//! - [`parse_input`] splits the range list and parses each to a pair of ids, which can be any [`Id`] integer type
 //! - [`find_invalid_ids_for_repeats`] finds invalid ids that repeat a given number of times
-//! - [`sum_invalid_id_pairs`] solves part 1 by mapping the ranges with [`find_invalid_ids_for_repeats`] specifically
-//!   for `repeats == 2`, and summing the values
 //! - [`find_invalid_ids_for_range`] finds invalid ids for all repeat variants from 2 up to the length of the maximum
 //!   value, and returns the unique ids
-//! - [`sum_invalid_ids`] solves part 2 by mapping the ranges with [`find_invalid_ids_for_range`] and summing
+//! - [`explain_invalid_ids_for_range`] adds every unit and repeat count that make each of those ids invalid, which
+//!   [`render_explanation`] reports per range
+//! - [`total_invalid_ids_for_repeats`] and [`total_invalid_ids_for_range`] count and sum the same ids without listing
+//!   them, treating each invalid id as its repeating unit multiplied by a number like `1001001`
+//! - [`merge_ranges`] merges overlapping ranges so no id is counted twice, and [`find_overlaps`] reports them
+//! - [`sum_invalid_id_pairs`] solves part 1 by totalling the ranges with [`total_invalid_ids_for_repeats`]
+//!   specifically for `repeats == 2`
+//! - [`sum_invalid_ids`] solves part 2 by totalling the ranges with [`total_invalid_ids_for_range`]
+//! - [`is_invalid`], [`next_invalid_id`], [`previous_invalid_id`], and [`nth_invalid_id`] answer questions about single
+//!   ids using the same units, without scanning for them
+//!
+//! Ids can be written in any [`Radix`] from 2 to 36, and ids and totals that don't fit in the chosen integer type are
+//! reported as an [`IdError`].
+//!
+//! The overlap and explanation reports are run with `gift-shop <radix> <path> --overlaps|--explain` for any file, or
+//! `run 2 <profile> --overlaps|--explain` for a profile's puzzle input.
 
+use crate::helpers::ranges::{Integer, IntervalSet};
 use itertools::Itertools;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
 use std::fs;
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Sub};
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-2-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-2/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 2.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-2-input.txt").expect("Failed to read file");
-    let ranges = parse_input(&contents);
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
+    let (pairs_sum, all_sum) = solve(&contents);
+
+    println!("The sum of invalid id pairs is {}", pairs_sum);
+
+    println!("The sum of all invalid ids is {}", all_sum);
+}
+
+/// The entry point for the `gift-shop <radix> <path> [--overlaps]` command, solving both parts for a list of ranges
+/// in the puzzle's format but with the ids written in another radix. The sums are written in the same radix. With
+/// `report_overlaps`, the ranges that share ids are listed first.
+pub fn run_with_radix(radix: u32, path: &str, report_overlaps: bool) {
+    let radix = Radix::new(radix);
+    let contents = fs::read_to_string(path).expect("Failed to read file");
+    let ranges = match parse_input::<u128>(&contents, radix) {
+        Ok(ranges) => ranges,
+        Err(err) => {
+            println!("Unable to sum the invalid ids: {}", err);
+            return;
+        }
+    };
+
+    if report_overlaps {
+        let overlaps = find_overlaps(&ranges);
+        if overlaps.is_empty() {
+            println!("No ranges overlap");
+        }
+        for RangeOverlap {
+            first,
+            second,
+            shared: (min, max),
+        } in overlaps
+        {
+            println!(
+                "Ranges {} and {} share {}-{}",
+                first + 1,
+                second + 1,
+                min.to_string_radix(radix),
+                max.to_string_radix(radix)
+            );
+        }
+    }
+
+    let sums = sum_invalid_id_pairs(&ranges, radix)
+        .and_then(|pairs_sum| Ok((pairs_sum, sum_invalid_ids(&ranges, radix)?)));
+
+    match sums {
+        Ok((pairs_sum, all_sum)) => {
+            println!(
+                "The sum of invalid id pairs is {} in base {}",
+                pairs_sum.to_string_radix(radix),
+                radix.0
+            );
+
+            println!(
+                "The sum of all invalid ids is {} in base {}",
+                all_sum.to_string_radix(radix),
+                radix.0
+            );
+        }
+        Err(err) => println!("Unable to sum the invalid ids: {}", err),
+    }
+}
+
+/// The entry point for the `gift-shop <radix> <path> --explain` command, listing each range's invalid ids with the
+/// units that repeat to give them. The ids are listed one by one, so this is only practical for narrow ranges.
+pub fn run_explain(radix: u32, path: &str) {
+    let radix = Radix::new(radix);
+    let contents = fs::read_to_string(path).expect("Failed to read file");
 
+    match parse_input::<u128>(&contents, radix) {
+        Ok(ranges) => print!("{}", render_explanation(&ranges, radix)),
+        Err(err) => println!("Unable to explain the invalid ids: {}", err),
+    }
+}
+
+/// Render a report with a heading per range, then a line per invalid id in the range, e.g. `222222 = 2×6, 22×3,
+/// 222×2`
+fn render_explanation<T: Id + Hash>(ranges: &[IdRange<T>], radix: Radix) -> String {
+    let mut output = String::new();
+
+    for (position, range @ &(min, max)) in ranges.iter().enumerate() {
+        writeln!(
+            output,
+            "Range {}: {}-{}",
+            position + 1,
+            min.to_string_radix(radix),
+            max.to_string_radix(radix)
+        )
+        .unwrap();
+
+        let explained = explain_invalid_ids_for_range(range, radix);
+        if explained.is_empty() {
+            writeln!(output, "  No invalid ids").unwrap();
+        }
+        for (id, units) in explained {
+            let units = units
+                .iter()
+                .map(|RepeatingUnit { unit, repeats }| {
+                    format!("{}×{}", unit.to_string_radix(radix), repeats)
+                })
+                .join(", ");
+            writeln!(output, "  {} = {}", id.to_string_radix(radix), units).unwrap();
+        }
+    }
+
+    output
+}
+
+/// The entry point for the `invalid-id <id> [radix]` command, explaining whether an id is invalid, and where it is
+/// among the invalid ids. The id is written in `radix`.
+pub fn run_invalid_id(id: &str, radix: u32) {
+    let radix = Radix::new(radix);
+    let Some(id) = <u128 as Id>::from_str_radix(id, radix) else {
+        println!("{}", IdError::Unparseable(id.to_string()));
+        return;
+    };
+    let write_id = |id: u128| id.to_string_radix(radix);
+
+    match is_invalid(id, radix) {
+        Some(RepeatingUnit { unit, repeats }) => println!(
+            "{} is invalid, as {} repeated {} times",
+            write_id(id),
+            write_id(unit),
+            repeats
+        ),
+        None => println!("{} is valid", write_id(id)),
+    }
     println!(
-        "The sum of invalid id pairs is {}",
-        sum_invalid_id_pairs(&ranges)
+        "There are {} invalid ids before it",
+        rank_invalid_id(id, radix)
     );
+    match previous_invalid_id(id, radix) {
+        Some(previous) => println!("The previous invalid id is {}", write_id(previous)),
+        None => println!("There are no invalid ids before it"),
+    }
+    match next_invalid_id(id, radix) {
+        Some(next) => println!("The next invalid id is {}", write_id(next)),
+        None => println!("There are no more invalid ids that fit in 128 bits"),
+    }
+}
+
+/// The entry point for the `invalid-id --nth <n> [radix]` command, finding the `n`th invalid id counting from zero.
+/// `n` is decimal, the id is written in `radix`.
+pub fn run_nth_invalid_id(n: u128, radix: u32) {
+    let radix = Radix::new(radix);
+
+    match nth_invalid_id(n, radix) {
+        Some(id) => println!("Invalid id {} is {}", n, id.to_string_radix(radix)),
+        None => println!("Invalid id {} doesn't fit in 128 bits", n),
+    }
+}
+
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted. The ids are read as
+/// `u128`s, so that ranges near `u64::MAX` are handled.
+pub fn solve(contents: &str) -> (String, String) {
+    let ranges: Vec<IdRange<u128>> =
+        parse_input(contents, Radix::DECIMAL).unwrap_or_else(|err| panic!("{}", err));
+
+    (
+        sum_invalid_id_pairs(&ranges, Radix::DECIMAL)
+            .unwrap_or_else(|err| panic!("{}", err))
+            .to_string(),
+        sum_invalid_ids(&ranges, Radix::DECIMAL)
+            .unwrap_or_else(|err| panic!("{}", err))
+            .to_string(),
+    )
+}
+
+/// The base that ids are written in, from 2 to 36 as with [`u64::from_str_radix`]. Digits past 9 are letters.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+struct Radix(u32);
+
+impl Radix {
+    /// The puzzle's ids are decimal
+    const DECIMAL: Radix = Radix(10);
+
+    fn new(radix: u32) -> Radix {
+        assert!(
+            (2..=36).contains(&radix),
+            "Radix must be from 2 to 36, not {}",
+            radix
+        );
+
+        Radix(radix)
+    }
+}
+
+/// The integer types that ids can be parsed to, with the arithmetic needed to find and total the invalid ids.
+/// Implemented for the unsigned primitive integers.
+trait Id:
+    Integer
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    /// The number of digits when written in `radix`, treating 0 as having one digit
+    fn digits(self, radix: Radix) -> u32;
+
+    /// `radix^exponent`, or `None` if that doesn't fit
+    fn checked_power(radix: Radix, exponent: u32) -> Option<Self>;
+
+    /// Parse an id written in `radix`, or `None` if it isn't valid or doesn't fit
+    fn from_str_radix(id: &str, radix: Radix) -> Option<Self>;
+
+    /// Write the id in `radix`, using lowercase letters for digits past 9
+    fn to_string_radix(self, radix: Radix) -> String;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
 
-    println!("The sum of all invalid ids is {}", sum_invalid_ids(&ranges));
+    fn div_ceil(self, other: Self) -> Self;
+
+    fn is_even(self) -> bool;
 }
 
+macro_rules! impl_id {
+    ($($t:ty),*) => {
+        $(impl Id for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$t>::MAX;
+
+            fn digits(self, Radix(radix): Radix) -> u32 {
+                self.checked_ilog(radix as $t).unwrap_or(0) + 1
+            }
+
+            fn checked_power(Radix(radix): Radix, exponent: u32) -> Option<Self> {
+                (radix as $t).checked_pow(exponent)
+            }
+
+            fn from_str_radix(id: &str, Radix(radix): Radix) -> Option<Self> {
+                <$t>::from_str_radix(id, radix).ok()
+            }
+
+            fn to_string_radix(self, Radix(radix): Radix) -> String {
+                let mut remaining = self;
+                let mut digits = Vec::new();
+                loop {
+                    digits.push(char::from_digit((remaining % radix as $t) as u32, radix).unwrap());
+                    remaining /= radix as $t;
+                    if remaining == 0 {
+                        break;
+                    }
+                }
+
+                digits.iter().rev().collect()
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn div_ceil(self, other: Self) -> Self {
+                <$t>::div_ceil(self, other)
+            }
+
+            fn is_even(self) -> bool {
+                self % 2 == 0
+            }
+        })*
+    };
+}
+
+impl_id!(u8, u16, u32, u64, u128, usize);
+
+/// The reasons ranges of ids can't be totalled
+#[derive(Debug, Eq, PartialEq)]
+enum IdError {
+    /// A bound of a range isn't a number in the radix, or doesn't fit in the integer type
+    Unparseable(String),
+    /// A count or sum doesn't fit in the integer type
+    Overflow(String),
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdError::Unparseable(id) => write!(f, "Unable to parse id: {}", id),
+            IdError::Overflow(total) => write!(f, "Overflow: {}", total),
+        }
+    }
+}
+
+impl Error for IdError {}
+
 /// Represents a range of numbers to check for invalid ids in the format `(min, max)` inclusive.
-type IdRange = (u64, u64);
+type IdRange<T = u64> = (T, T);
+
+/// The number of invalid ids found, and their sum
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+struct InvalidIdTotals<T> {
+    count: T,
+    sum: T,
+}
+
+impl<T: Id> InvalidIdTotals<T> {
+    fn zero() -> Self {
+        InvalidIdTotals {
+            count: T::ZERO,
+            sum: T::ZERO,
+        }
+    }
+
+    fn checked_add(self, other: Self) -> Result<Self, IdError> {
+        match (
+            self.count.checked_add(other.count),
+            self.sum.checked_add(other.sum),
+        ) {
+            (Some(count), Some(sum)) => Ok(InvalidIdTotals { count, sum }),
+            _ => Err(IdError::Overflow(format!("{} + {}", self.sum, other.sum))),
+        }
+    }
+}
+
+/// The units from `first` to `last` inclusive, which repeat to give ids of a given length when multiplied by
+/// `multiplier`
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+struct UnitRange<T> {
+    first: T,
+    last: T,
+    multiplier: T,
+}
+
+impl<T: Id> UnitRange<T> {
+    fn count(&self) -> T {
+        self.last - self.first + T::ONE
+    }
+}
+
+/// Why an id is invalid, as its digits being `unit` repeated `repeats` times, e.g. `123123` is `123` twice
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+struct RepeatingUnit<T> {
+    unit: T,
+    repeats: u32,
+}
+
+/// Two ranges in the input that share ids, by their positions in the input counting from zero
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+struct RangeOverlap<T> {
+    first: usize,
+    second: usize,
+    shared: IdRange<T>,
+}
 
 /// The input is a comma-separated list of ranges in the format `{min}-{max}`, which are each parsed into an [`IdRange`]
 ///
-/// `parse_input(&"1-3,10-11".to_string())` would give `vec![(1,3), (10,11)]`.
-fn parse_input(input: &String) -> Vec<IdRange> {
+/// `parse_input("1-3,10-11", Radix::DECIMAL)` would give `vec![(1,3), (10,11)]`.
+fn parse_input<T: Id>(input: &str, radix: Radix) -> Result<Vec<IdRange<T>>, IdError> {
+    let parse_id =
+        |id: &str| T::from_str_radix(id, radix).ok_or_else(|| IdError::Unparseable(id.to_string()));
+
     input
         .trim()
         .split(",")
         .map(|range| {
-            let (min, max) = range.split_once("-").unwrap();
-            (min.parse().unwrap(), max.parse().unwrap())
+            let (min, max) = range
+                .split_once("-")
+                .ok_or_else(|| IdError::Unparseable(range.to_string()))?;
+            Ok((parse_id(min)?, parse_id(max)?))
         })
         .collect()
 }
@@ -49,21 +415,32 @@ fn parse_input(input: &String) -> Vec<IdRange> {
 ///
 /// - `123123` is invalid when repeats is `2` because it is `123` twice in a row,
 /// - `121212` is invalid when repeats is `3`
-///   and so on.
+///   and so on. The repeats are of the digits in `radix`, so `0x1212` is invalid in hex but not in decimal, as `4626`.
 ///
 /// Starting from the first part of the number only works if the range has numbers with a uniform magnitude. This
-/// splits ranges up to enforce this,
-fn find_invalid_ids_for_repeats(&(min, max): &IdRange, repeats: u32) -> Vec<u64> {
-    fn find_invalid_ids_for_subrange(&(min, max): &IdRange, repeats: u32) -> Vec<u64> {
-        let starting_exponent = (min.ilog10()) / repeats;
-        let first_part_of_number = min / 10u64.pow(min.ilog10() - starting_exponent);
+/// splits ranges up to enforce this, by intersecting the range with each magnitude that has a length divisible by
+/// `repeats`. Repeating a unit can give a number too big for the integer type, but it would also be past the end of
+/// the range, so the search stops there.
+///
+/// This lists the ids that [`total_invalid_ids_for_repeats`] counts, and is the reference it is tested against.
+fn find_invalid_ids_for_repeats<T: Id>(
+    &(min, max): &IdRange<T>,
+    repeats: u32,
+    radix: Radix,
+) -> Vec<T> {
+    fn find_invalid_ids_for_subrange<T: Id>(
+        &(min, max): &IdRange<T>,
+        repeats: u32,
+        radix: Radix,
+    ) -> Vec<T> {
+        let starting_digits = min.digits(radix).div_ceil(repeats);
+        let first_part_of_number =
+            min / T::checked_power(radix, min.digits(radix) - starting_digits).unwrap();
 
-        (first_part_of_number..)
+        std::iter::successors(Some(first_part_of_number), |&base| base.successor())
             .map(|base| {
-                format!("{base}")
-                    .repeat(repeats as usize)
-                    .parse::<u64>()
-                    .ok()
+                let invalid_id = base.to_string_radix(radix).repeat(repeats as usize);
+                T::from_str_radix(&invalid_id, radix)
             })
             .while_some()
             .skip_while(|&invalid_id| invalid_id < min)
@@ -71,46 +448,359 @@ fn find_invalid_ids_for_repeats(&(min, max): &IdRange, repeats: u32) -> Vec<u64>
             .collect()
     }
 
-    (min.ilog10()..=max.ilog10())
-        .filter(|exponent| (exponent + 1) % repeats == 0)
-        .flat_map(|exponent| {
-            let subrange = (
-                min.max(10u64.pow(exponent)),
-                max.min(10u64.pow(exponent + 1) - 1),
-            );
-            find_invalid_ids_for_subrange(&subrange, repeats)
+    let magnitudes: IntervalSet<T> = (min.digits(radix)..=max.digits(radix))
+        .filter(|digits| digits % repeats == 0)
+        .map(|digits| {
+            let first = T::checked_power(radix, digits - 1).unwrap();
+            let last = T::checked_power(radix, digits).map_or(max, |limit| limit - T::ONE);
+            (first, last)
         })
+        .collect();
+
+    IntervalSet::from((min, max))
+        .intersection(&magnitudes)
+        .iter()
+        .flat_map(|subrange| find_invalid_ids_for_subrange(&subrange, repeats, radix))
         .collect()
 }
 
 /// Iterate through all the possible repeat variants for a range, and return the unique invalid ids
-fn find_invalid_ids_for_range(range: &IdRange) -> Vec<u64> {
-    (2..=range.1.ilog10() + 1)
-        .flat_map(|repeats| find_invalid_ids_for_repeats(range, repeats))
+fn find_invalid_ids_for_range<T: Id + Hash>(range: &IdRange<T>, radix: Radix) -> Vec<T> {
+    (2..=range.1.digits(radix))
+        .flat_map(|repeats| find_invalid_ids_for_repeats(range, repeats, radix))
         .unique()
         .collect()
 }
 
-/// Solves part 1 by summing ids that are invalid due to being composed of a pair of numbers
-fn sum_invalid_id_pairs(ranges: &Vec<IdRange>) -> u64 {
+/// As [`find_invalid_ids_for_range`], but with the breakdown of why each id is invalid from [`repeating_units`]. The
+/// ids are in ascending order.
+fn explain_invalid_ids_for_range<T: Id + Hash>(
+    range: &IdRange<T>,
+    radix: Radix,
+) -> Vec<(T, Vec<RepeatingUnit<T>>)> {
+    find_invalid_ids_for_range(range, radix)
+        .into_iter()
+        .sorted()
+        .map(|id| (id, repeating_units(id, radix).collect()))
+        .collect()
+}
+
+/// The units that give an id with `digits` digits in `min..=max` when repeated `repeats` times, or `None` if there
+/// are none.
+///
+/// Such an id is its unit multiplied by `(radix^digits - 1) / (radix^unit_digits - 1)`, e.g. `1001001` for a
+/// three-digit unit repeated three times in any radix. So the units that give an id in the range are themselves a
+/// range, found by dividing the bounds.
+///
+/// `radix^digits` can be too big for the integer type when the range reaches its maximum, so the multiplier is built
+/// up as `1 + radix^unit_digits + radix^(2 * unit_digits) + ...` instead, which fits as it is smaller than any id.
+fn units_for_digits<T: Id>(
+    range: &IdRange<T>,
+    digits: u32,
+    repeats: u32,
+    radix: Radix,
+) -> Option<UnitRange<T>> {
+    let unit_digits = digits / repeats;
+    let unit_limit = T::checked_power(radix, unit_digits).unwrap();
+    let multiplier = (1..repeats).fold(T::ONE, |multiplier, repeat| {
+        multiplier + T::checked_power(radix, unit_digits * repeat).unwrap()
+    });
+
+    let (min, max) = clamp_to_digits(range, digits, radix);
+    let first = min
+        .div_ceil(multiplier)
+        .max(unit_limit / T::checked_power(radix, 1).unwrap());
+    let last = (max / multiplier).min(unit_limit - T::ONE);
+
+    (min <= max && first <= last).then_some(UnitRange {
+        first,
+        last,
+        multiplier,
+    })
+}
+
+/// The part of a range with `digits` digits, which is empty if `min > max`
+fn clamp_to_digits<T: Id>(&(min, max): &IdRange<T>, digits: u32, radix: Radix) -> IdRange<T> {
+    (
+        min.max(T::checked_power(radix, digits - 1).unwrap()),
+        T::checked_power(radix, digits).map_or(max, |limit| max.min(limit - T::ONE)),
+    )
+}
+
+/// The number of ids with `digits` digits in `min..=max` that are a unit repeated `repeats` times, and their sum. The
+/// ids are summed as an arithmetic series of the [`units_for_digits`] times the multiplier.
+fn totals_for_digits<T: Id>(
+    range: &IdRange<T>,
+    digits: u32,
+    repeats: u32,
+    radix: Radix,
+) -> Result<InvalidIdTotals<T>, IdError> {
+    let Some(units) = units_for_digits(range, digits, repeats, radix) else {
+        return Ok(InvalidIdTotals::zero());
+    };
+
+    // One of the count and the sum of the first and last units is even, so halve that before multiplying
+    let count = units.count();
+    let first_plus_last = units.first + units.last;
+    let unit_sum = if count.is_even() {
+        (count / (T::ONE + T::ONE)).checked_mul(first_plus_last)
+    } else {
+        count.checked_mul(first_plus_last / (T::ONE + T::ONE))
+    };
+
+    unit_sum
+        .and_then(|unit_sum| unit_sum.checked_mul(units.multiplier))
+        .map(|sum| InvalidIdTotals { count, sum })
+        .ok_or_else(|| {
+            let (min, max) = clamp_to_digits(range, digits, radix);
+            IdError::Overflow(format!(
+                "the sum of the {}-digit ids from {} to {} repeated {} times",
+                digits, min, max, repeats
+            ))
+        })
+}
+
+/// The lengths of the ids in a range that can be split into `repeats` equal parts
+fn digit_counts<T: Id>(
+    &(min, max): &IdRange<T>,
+    repeats: u32,
+    radix: Radix,
+) -> impl Iterator<Item = u32> {
+    (min.digits(radix)..=max.digits(radix)).filter(move |digits| digits % repeats == 0)
+}
+
+/// Counts and sums the ids in a range that are composed of a smaller number repeated `repeats` times, without listing
+/// them as [`find_invalid_ids_for_repeats`] does
+fn total_invalid_ids_for_repeats<T: Id>(
+    range: &IdRange<T>,
+    repeats: u32,
+    radix: Radix,
+) -> Result<InvalidIdTotals<T>, IdError> {
+    digit_counts(range, repeats, radix).try_fold(InvalidIdTotals::zero(), |total, digits| {
+        total.checked_add(totals_for_digits(range, digits, repeats, radix)?)
+    })
+}
+
+/// The Möbius function of `n`, which is `0` if `n` has a repeated prime factor, otherwise `1` or `-1` for an even or
+/// odd number of prime factors
+fn mobius(mut n: u32) -> i8 {
+    let mut sign = 1;
+    let mut factor = 2;
+    while n > 1 {
+        if n.is_multiple_of(factor) {
+            n /= factor;
+            if n.is_multiple_of(factor) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        factor += 1;
+    }
+
+    sign
+}
+
+/// Counts and sums the ids in a range composed of any repeating pattern, without listing them as
+/// [`find_invalid_ids_for_range`] does.
+///
+/// An id that repeats with several counts would be counted more than once by adding up the totals for each count, e.g.
+/// `222222` is `2` six times, `22` three times, and `222` twice. An id repeated `a` times and `b` times is also
+/// repeated `lcm(a, b)` times, so inclusion–exclusion over the repeat counts counts it once. This works out as adding
+/// the totals for each repeat count weighted by minus its [`mobius`] value, which also skips repeat counts with a
+/// squared factor as those ids are already counted by a smaller count.
+///
+/// The ids counted more than once are added before they are taken away, so this can overflow even if the final total
+/// would fit.
+fn total_invalid_ids_for_range<T: Id>(
+    range: &IdRange<T>,
+    radix: Radix,
+) -> Result<InvalidIdTotals<T>, IdError> {
+    let mut added = InvalidIdTotals::zero();
+    let mut removed = InvalidIdTotals::zero();
+
+    for repeats in 2..=range.1.digits(radix) {
+        let totals = total_invalid_ids_for_repeats(range, repeats, radix)?;
+        match mobius(repeats) {
+            -1 => added = added.checked_add(totals)?,
+            1 => removed = removed.checked_add(totals)?,
+            _ => {}
+        }
+    }
+
+    Ok(InvalidIdTotals {
+        count: added.count - removed.count,
+        sum: added.sum - removed.sum,
+    })
+}
+
+/// Counts the ids in a range composed of any repeating pattern, as [`total_invalid_ids_for_range`] does but without
+/// their sum, so that it can't overflow
+fn count_invalid_ids_for_range<T: Id>(range: &IdRange<T>, radix: Radix) -> T {
+    let mut added = T::ZERO;
+    let mut removed = T::ZERO;
+
+    for repeats in 2..=range.1.digits(radix) {
+        let count = digit_counts(range, repeats, radix)
+            .filter_map(|digits| units_for_digits(range, digits, repeats, radix))
+            .fold(T::ZERO, |count, units| count + units.count());
+        match mobius(repeats) {
+            -1 => added = added + count,
+            1 => removed = removed + count,
+            _ => {}
+        }
+    }
+
+    added - removed
+}
+
+/// The number of times an id with `digits` digits could repeat a unit, starting with the shortest unit
+fn repeat_counts(digits: u32) -> impl Iterator<Item = u32> {
+    (1..digits)
+        .filter(move |unit_digits| digits.is_multiple_of(*unit_digits))
+        .map(move |unit_digits| digits / unit_digits)
+}
+
+/// Every unit that repeats to give `id`, smallest first, e.g. `222222` is `2` six times, `22` three times, and `222`
+/// twice. There are none if the id is valid.
+fn repeating_units<T: Id>(id: T, radix: Radix) -> impl Iterator<Item = RepeatingUnit<T>> {
+    let digits = id.digits(radix);
+
+    repeat_counts(digits).filter_map(move |repeats| {
+        units_for_digits(&(id, id), digits, repeats, radix).map(|units| RepeatingUnit {
+            unit: units.first,
+            repeats,
+        })
+    })
+}
+
+/// Checks a single id, returning the smallest unit it repeats if it is invalid. An id that repeats several units
+/// always repeats the smallest, as the others are made of it.
+fn is_invalid<T: Id>(id: T, radix: Radix) -> Option<RepeatingUnit<T>> {
+    repeating_units(id, radix).next()
+}
+
+/// The first invalid id after `id`, or `None` if it doesn't fit in the integer type. For each length from that of
+/// `id + 1`, the first unit of each length that repeats to give an id after `id` is a candidate, and the smallest of
+/// those is the next id. Lengths without any candidates are skipped, e.g. `999` to `1010` has no 3-digit candidates.
+fn next_invalid_id<T: Id>(id: T, radix: Radix) -> Option<T> {
+    let start = id.successor()?;
+
+    (start.digits(radix)..)
+        .map_while(|digits| T::checked_power(radix, digits - 1).map(|_| digits))
+        .find_map(|digits| {
+            repeat_counts(digits)
+                .filter_map(|repeats| units_for_digits(&(start, T::MAX), digits, repeats, radix))
+                .map(|units| units.first * units.multiplier)
+                .min()
+        })
+}
+
+/// The last invalid id before `id`, or `None` if there isn't one, as [`next_invalid_id`] but searching down from the
+/// length of `id - 1`
+fn previous_invalid_id<T: Id>(id: T, radix: Radix) -> Option<T> {
+    let end = id.predecessor()?;
+
+    (2..=end.digits(radix)).rev().find_map(|digits| {
+        repeat_counts(digits)
+            .filter_map(|repeats| units_for_digits(&(T::ZERO, end), digits, repeats, radix))
+            .map(|units| units.last * units.multiplier)
+            .max()
+    })
+}
+
+/// The number of invalid ids before `id`, so its position in the list of all invalid ids if it is one
+fn rank_invalid_id<T: Id>(id: T, radix: Radix) -> T {
+    id.predecessor().map_or(T::ZERO, |end| {
+        count_invalid_ids_for_range(&(T::ZERO, end), radix)
+    })
+}
+
+/// The `n`th invalid id counting from zero, e.g. `11` then `22` in decimal, or `None` if it doesn't fit in the integer
+/// type.
+///
+/// Whole lengths of ids are skipped by counting their invalid ids, then the id is found by a binary search within its
+/// length for the first id with `n` invalid ids before it in the list.
+fn nth_invalid_id<T: Id>(n: T, radix: Radix) -> Option<T> {
+    let mut remaining = n;
+    let mut digits = 2;
+
+    loop {
+        let first = T::checked_power(radix, digits - 1)?;
+        let last = T::checked_power(radix, digits).map_or(T::MAX, |limit| limit - T::ONE);
+        let count = count_invalid_ids_for_range(&(first, last), radix);
+
+        if remaining < count {
+            let (mut low, mut high) = (first, last);
+            while low < high {
+                let middle = low + (high - low) / (T::ONE + T::ONE);
+                if count_invalid_ids_for_range(&(first, middle), radix) > remaining {
+                    high = middle;
+                } else {
+                    low = middle + T::ONE;
+                }
+            }
+
+            return Some(low);
+        }
+
+        remaining = remaining - count;
+        digits += 1;
+    }
+}
+
+/// Merges ranges that overlap or touch, so that an id in more than one range of the input is only counted once. The
+/// merged ranges are in ascending order.
+fn merge_ranges<T: Id>(ranges: &[IdRange<T>]) -> Vec<IdRange<T>> {
     ranges
         .iter()
-        .flat_map(|range| find_invalid_ids_for_repeats(range, 2))
-        .sum()
+        .copied()
+        .collect::<IntervalSet<T>>()
+        .iter()
+        .collect()
 }
 
-/// Solves part 2 by summing ids that are invalid due to being composed of any repeating pattern of numbers
-fn sum_invalid_ids(ranges: &Vec<IdRange>) -> u64 {
+/// Lists every pair of ranges in the input that share ids, in the order they appear
+fn find_overlaps<T: Id>(ranges: &[IdRange<T>]) -> Vec<RangeOverlap<T>> {
     ranges
         .iter()
-        .flat_map(|range| find_invalid_ids_for_range(range))
-        .sum()
+        .enumerate()
+        .tuple_combinations()
+        .filter_map(|((first, &(min_a, max_a)), (second, &(min_b, max_b)))| {
+            let shared = (min_a.max(min_b), max_a.min(max_b));
+            (shared.0 <= shared.1).then_some(RangeOverlap {
+                first,
+                second,
+                shared,
+            })
+        })
+        .collect()
+}
+
+/// Solves part 1 by summing ids that are invalid due to being composed of a pair of numbers
+fn sum_invalid_id_pairs<T: Id>(ranges: &[IdRange<T>], radix: Radix) -> Result<T, IdError> {
+    merge_ranges(ranges)
+        .iter()
+        .try_fold(InvalidIdTotals::zero(), |total, range| {
+            total.checked_add(total_invalid_ids_for_repeats(range, 2, radix)?)
+        })
+        .map(|total| total.sum)
+}
+
+/// Solves part 2 by summing ids that are invalid due to being composed of any repeating pattern of numbers
+fn sum_invalid_ids<T: Id>(ranges: &[IdRange<T>], radix: Radix) -> Result<T, IdError> {
+    merge_ranges(ranges)
+        .iter()
+        .try_fold(InvalidIdTotals::zero(), |total, range| {
+            total.checked_add(total_invalid_ids_for_range(range, radix)?)
+        })
+        .map(|total| total.sum)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_2::*;
     use crate::helpers::test::assert_contains_in_any_order;
+    use proptest::prelude::*;
 
     fn sample_input() -> String {
         "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,\
@@ -136,7 +826,10 @@ mod tests {
 
     #[test]
     fn can_parse_input() {
-        assert_eq!(parse_input(&sample_input()), sample_ranges());
+        assert_eq!(
+            parse_input(&sample_input(), Radix::DECIMAL),
+            Ok(sample_ranges())
+        );
     }
 
     #[test]
@@ -156,7 +849,7 @@ mod tests {
         .into_iter()
         .for_each(|(range, invalid_ids)| {
             assert_eq!(
-                find_invalid_ids_for_repeats(&range, 2),
+                find_invalid_ids_for_repeats(&range, 2, Radix::DECIMAL),
                 invalid_ids,
                 "find_invalid_ids_for_repeats(&{range:?}, 2) should produce {invalid_ids:?}"
             );
@@ -179,17 +872,557 @@ mod tests {
         ]
         .into_iter()
         .for_each(|(range, invalid_ids)| {
-            assert_contains_in_any_order(find_invalid_ids_for_range(&range), invalid_ids);
+            assert_contains_in_any_order(
+                find_invalid_ids_for_range(&range, Radix::DECIMAL),
+                invalid_ids,
+            );
         });
     }
 
     #[test]
     fn can_sum_invalid_ids_for_pairs_in_range_list() {
-        assert_eq!(sum_invalid_id_pairs(&sample_ranges()), 1227775554);
+        assert_eq!(
+            sum_invalid_id_pairs(&sample_ranges(), Radix::DECIMAL),
+            Ok(1227775554)
+        );
     }
 
     #[test]
     fn can_sum_invalid_ids_for_range_list() {
-        assert_eq!(sum_invalid_ids(&sample_ranges()), 4174379265);
+        assert_eq!(
+            sum_invalid_ids(&sample_ranges(), Radix::DECIMAL),
+            Ok(4174379265)
+        );
+    }
+
+    #[test]
+    fn can_merge_overlapping_ranges() {
+        let ranges = vec![(50u64, 150), (11, 22), (10, 100), (11, 22)];
+
+        assert_eq!(merge_ranges(&ranges), vec![(10, 150)]);
+        assert_eq!(
+            sum_invalid_id_pairs(&ranges, Radix::DECIMAL),
+            Ok(11 + 22 + 33 + 44 + 55 + 66 + 77 + 88 + 99)
+        );
+        assert_eq!(sum_invalid_ids(&ranges, Radix::DECIMAL), Ok(495 + 111));
+        assert_eq!(
+            find_overlaps(&ranges),
+            vec![
+                RangeOverlap {
+                    first: 0,
+                    second: 2,
+                    shared: (50, 100)
+                },
+                RangeOverlap {
+                    first: 1,
+                    second: 2,
+                    shared: (11, 22)
+                },
+                RangeOverlap {
+                    first: 1,
+                    second: 3,
+                    shared: (11, 22)
+                },
+                RangeOverlap {
+                    first: 2,
+                    second: 3,
+                    shared: (11, 22)
+                },
+            ]
+        );
+        assert_eq!(find_overlaps(&sample_ranges()), vec![]);
+    }
+
+    #[test]
+    fn can_total_invalid_ids_for_digit_count() {
+        // 1010, 1111, ..., 9999, which are the units 10 to 99 multiplied by 101
+        assert_eq!(
+            totals_for_digits(&(1u64, 100_000), 4, 2, Radix::DECIMAL).unwrap(),
+            InvalidIdTotals {
+                count: 90,
+                sum: 101 * (10 + 99) * 90 / 2
+            }
+        );
+        assert_eq!(
+            totals_for_digits(&(1000u64, 1200), 4, 2, Radix::DECIMAL).unwrap(),
+            InvalidIdTotals {
+                count: 2,
+                sum: 1010 + 1111
+            }
+        );
+        assert_eq!(
+            totals_for_digits(&(123_124u64, 123_999), 6, 3, Radix::DECIMAL).unwrap(),
+            InvalidIdTotals::zero()
+        );
+    }
+
+    #[test]
+    fn can_total_invalid_ids() {
+        assert_eq!(
+            total_invalid_ids_for_repeats(&(95u64, 1012), 2, Radix::DECIMAL).unwrap(),
+            InvalidIdTotals {
+                count: 2,
+                sum: 99 + 1010
+            }
+        );
+        // 99, then 111, 222, ..., 999, then 1010
+        assert_eq!(
+            total_invalid_ids_for_range(&(95u64, 1012), Radix::DECIMAL).unwrap(),
+            InvalidIdTotals {
+                count: 11,
+                sum: 99 + 111 * 45 + 1010
+            }
+        );
+
+        // `2` six times, `22` three times, and `222` twice, but only counted once
+        assert_eq!(
+            total_invalid_ids_for_range(&(222_222u64, 222_222), Radix::DECIMAL).unwrap(),
+            InvalidIdTotals {
+                count: 1,
+                sum: 222_222
+            }
+        );
+    }
+
+    #[test]
+    fn can_total_wide_ranges() {
+        // An id with a repeating pattern repeats its shortest unit, which doesn't repeat itself. Count those units for
+        // each length, by removing the units of each length that divides it from all the numbers of that length.
+        let mut unrepeated_units: Vec<u128> = vec![0];
+        for digits in 1..=19u32 {
+            let repeated: u128 = (1..digits)
+                .filter(|unit_digits| digits % unit_digits == 0)
+                .map(|unit_digits| unrepeated_units[unit_digits as usize])
+                .sum();
+            unrepeated_units.push(9 * 10u128.pow(digits - 1) - repeated);
+        }
+        let expected_count: u128 = (1..=19u32)
+            .flat_map(|digits| (1..digits).filter(move |unit_digits| digits % unit_digits == 0))
+            .map(|unit_digits| unrepeated_units[unit_digits as usize])
+            .sum();
+
+        assert_eq!(
+            total_invalid_ids_for_range(&(1u128, 10u128.pow(19) - 1), Radix::DECIMAL)
+                .unwrap()
+                .count,
+            expected_count
+        );
+        assert_eq!(
+            total_invalid_ids_for_range(&(10u64, 99), Radix::DECIMAL).unwrap(),
+            InvalidIdTotals { count: 9, sum: 495 }
+        );
+        assert_eq!(
+            total_invalid_ids_for_range(&(1u64, 9), Radix::DECIMAL).unwrap(),
+            InvalidIdTotals::zero()
+        );
+    }
+
+    #[test]
+    fn can_total_ids_beyond_u64() {
+        let ranges: Vec<IdRange<u128>> =
+            parse_input("18446744073709551610-18446744073709551620", Radix::DECIMAL).unwrap();
+
+        assert_eq!(ranges, vec![(u64::MAX as u128 - 5, u64::MAX as u128 + 5)]);
+        assert_eq!(sum_invalid_ids(&ranges, Radix::DECIMAL), Ok(0));
+
+        // 20-digit ids are beyond u64, e.g. 10000000001000000000 is 1000000000 twice
+        let range = (10u128.pow(19), 10u128.pow(19) + 10u128.pow(10));
+        assert_eq!(
+            total_invalid_ids_for_repeats(&range, 2, Radix::DECIMAL),
+            Ok(InvalidIdTotals {
+                count: 1,
+                sum: 10000000001000000000
+            })
+        );
+        assert_eq!(
+            find_invalid_ids_for_repeats(&range, 2, Radix::DECIMAL),
+            vec![10000000001000000000]
+        );
+
+        // The range reaches the top of u128, where `10^digits` doesn't fit
+        let top = (u128::MAX - 10u128.pow(20), u128::MAX);
+        assert_eq!(
+            total_invalid_ids_for_range(&top, Radix::DECIMAL).map(|totals| totals.count),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn cannot_parse_ids_too_big_for_type() {
+        assert_eq!(
+            parse_input::<u32>("1-4294967296", Radix::DECIMAL),
+            Err(IdError::Unparseable("4294967296".to_string()))
+        );
+        assert_eq!(
+            parse_input::<u64>("1-2,3", Radix::DECIMAL),
+            Err(IdError::Unparseable("3".to_string()))
+        );
+    }
+
+    #[test]
+    fn cannot_total_ids_past_overflow() {
+        // 9999 is the last invalid id, but 1111 + 2222 + ... already overflows
+        assert_eq!(
+            total_invalid_ids_for_range(&(1000u16, 9999), Radix::DECIMAL),
+            Err(IdError::Overflow(
+                "the sum of the 4-digit ids from 1000 to 9999 repeated 2 times".to_string()
+            ))
+        );
+        assert_eq!(
+            sum_invalid_ids(
+                &[
+                    (3_000_030_000u32, 3_000_030_000),
+                    (2_000_020_000, 2_000_020_000)
+                ],
+                Radix::DECIMAL
+            ),
+            Err(IdError::Overflow("2000020000 + 3000030000".to_string()))
+        );
+        assert_eq!(
+            sum_invalid_id_pairs(&[(1u16, 999)], Radix::DECIMAL),
+            Ok(11 + 22 + 33 + 44 + 55 + 66 + 77 + 88 + 99)
+        );
+    }
+
+    #[test]
+    fn can_parse_other_radixes() {
+        assert_eq!(
+            parse_input::<u64>("a-FF,10-1z", Radix::new(36)),
+            Ok(vec![(10, 555), (36, 71)])
+        );
+        assert_eq!(
+            parse_input::<u64>("10-12", Radix::new(2)),
+            Err(IdError::Unparseable("12".to_string()))
+        );
+        assert_eq!(0x1a2bu64.to_string_radix(Radix::new(16)), "1a2b");
+        assert_eq!(0u64.to_string_radix(Radix::new(2)), "0");
+    }
+
+    #[test]
+    #[should_panic(expected = "Radix must be from 2 to 36, not 37")]
+    fn cannot_use_radix_past_36() {
+        Radix::new(37);
+    }
+
+    #[test]
+    fn can_find_invalid_ids_in_other_radixes() {
+        let hex = Radix::new(16);
+        assert_eq!(
+            find_invalid_ids_for_repeats(&(0x1200u64, 0x1300), 2, hex),
+            vec![0x1212]
+        );
+        assert_eq!(
+            find_invalid_ids_for_repeats(&(4600u64, 4700), 2, Radix::DECIMAL),
+            vec![4646]
+        );
+
+        // 11, 111, 1010, and 1111 which is also 1 four times
+        let binary = Radix::new(2);
+        assert_eq!(
+            total_invalid_ids_for_range(&(2u64, 15), binary),
+            Ok(InvalidIdTotals { count: 4, sum: 35 })
+        );
+        assert_eq!(
+            sum_invalid_id_pairs(&[(0b10u8, 0b1111), (0b100000, 0b111111)], binary),
+            Ok(3 + 10 + 15 + 0b100100 + 0b101101 + 0b110110 + 0b111111)
+        );
+    }
+
+    #[test]
+    fn can_calculate_mobius() {
+        assert_eq!(
+            (1..=12).map(mobius).collect::<Vec<_>>(),
+            vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]
+        );
+    }
+
+    #[test]
+    fn can_check_single_ids() {
+        assert_eq!(
+            is_invalid(222_222u64, Radix::DECIMAL),
+            Some(RepeatingUnit {
+                unit: 2,
+                repeats: 6
+            })
+        );
+        assert_eq!(
+            is_invalid(123_123u64, Radix::DECIMAL),
+            Some(RepeatingUnit {
+                unit: 123,
+                repeats: 2
+            })
+        );
+        assert_eq!(
+            is_invalid(1010u64, Radix::DECIMAL),
+            Some(RepeatingUnit {
+                unit: 10,
+                repeats: 2
+            })
+        );
+        assert_eq!(is_invalid(1011u64, Radix::DECIMAL), None);
+        assert_eq!(is_invalid(7u64, Radix::DECIMAL), None);
+        assert_eq!(
+            is_invalid(0x1212u64, Radix::new(16)),
+            Some(RepeatingUnit {
+                unit: 0x12,
+                repeats: 2
+            })
+        );
+    }
+
+    #[test]
+    fn can_explain_invalid_ids() {
+        let unit = |unit, repeats| RepeatingUnit { unit, repeats };
+
+        assert_eq!(
+            explain_invalid_ids_for_range(&(222_220u64, 222_224), Radix::DECIMAL),
+            vec![(222_222, vec![unit(2, 6), unit(22, 3), unit(222, 2)])]
+        );
+        assert_eq!(
+            explain_invalid_ids_for_range(&(95u64, 115), Radix::DECIMAL),
+            vec![(99, vec![unit(9, 2)]), (111, vec![unit(1, 3)])]
+        );
+        assert_eq!(
+            explain_invalid_ids_for_range(&(1_698_522u64, 1_698_528), Radix::DECIMAL),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn can_render_explanation() {
+        let ranges = [(95u64, 115), (1_698_522, 1_698_528), (0b1111, 0b1111)];
+
+        assert_eq!(
+            render_explanation(&ranges[..2], Radix::DECIMAL),
+            "\
+Range 1: 95-115
+  99 = 9×2
+  111 = 1×3
+Range 2: 1698522-1698528
+  No invalid ids
+"
+        );
+        assert_eq!(
+            render_explanation(&ranges[2..], Radix::new(2)),
+            "\
+Range 1: 1111-1111
+  1111 = 1×4, 11×2
+"
+        );
+    }
+
+    #[test]
+    fn can_find_neighbouring_invalid_ids() {
+        assert_eq!(next_invalid_id(0u64, Radix::DECIMAL), Some(11));
+        assert_eq!(next_invalid_id(22u64, Radix::DECIMAL), Some(33));
+        assert_eq!(next_invalid_id(99u64, Radix::DECIMAL), Some(111));
+        assert_eq!(next_invalid_id(999u64, Radix::DECIMAL), Some(1010));
+        assert_eq!(next_invalid_id(222u8, Radix::DECIMAL), None);
+
+        assert_eq!(previous_invalid_id(111u64, Radix::DECIMAL), Some(99));
+        assert_eq!(previous_invalid_id(1010u64, Radix::DECIMAL), Some(999));
+        assert_eq!(previous_invalid_id(11u64, Radix::DECIMAL), None);
+        assert_eq!(previous_invalid_id(0u64, Radix::DECIMAL), None);
+        assert_eq!(previous_invalid_id(u8::MAX, Radix::DECIMAL), Some(222));
+    }
+
+    #[test]
+    fn can_find_nth_invalid_id() {
+        assert_eq!(nth_invalid_id(0u64, Radix::DECIMAL), Some(11));
+        assert_eq!(nth_invalid_id(8u64, Radix::DECIMAL), Some(99));
+        assert_eq!(nth_invalid_id(9u64, Radix::DECIMAL), Some(111));
+        assert_eq!(rank_invalid_id(111u64, Radix::DECIMAL), 9);
+        assert_eq!(rank_invalid_id(0u64, Radix::DECIMAL), 0);
+
+        // 11 to 99, then 111 and 222 are all that fit in a u8
+        assert_eq!(nth_invalid_id(10u8, Radix::DECIMAL), Some(222));
+        assert_eq!(nth_invalid_id(11u8, Radix::DECIMAL), None);
+
+        // The last invalid id that fits in a u64 is 18446744071844674407, as 1844674407 twice
+        let count = count_invalid_ids_for_range(&(0u64, u64::MAX), Radix::DECIMAL);
+        assert_eq!(
+            nth_invalid_id(count - 1, Radix::DECIMAL),
+            Some(18_446_744_071_844_674_407)
+        );
+        assert_eq!(nth_invalid_id(count, Radix::DECIMAL), None);
+    }
+
+    /// The oracle for invalid ids, checking whether the id's digits are a unit repeated exactly `repeats` times
+    fn is_repeated(id: u64, repeats: usize) -> bool {
+        let digits = id.to_string();
+        digits.len().is_multiple_of(repeats)
+            && digits == digits[..digits.len() / repeats].repeat(repeats)
+    }
+
+    /// The oracle for invalid ids in other radixes, checking whether the id's digits have a repeating unit
+    fn is_invalid_in_radix(id: u64, radix: Radix) -> bool {
+        let digits = id.to_string_radix(radix);
+        (2..=digits.len()).any(|repeats| {
+            digits.len().is_multiple_of(repeats)
+                && digits == digits[..digits.len() / repeats].repeat(repeats)
+        })
+    }
+
+    /// Ranges of up to 2000 ids, which often cross a power of ten
+    fn id_range() -> impl Strategy<Value = IdRange> {
+        (1..=10u32, 1..10_000u64, 0..2000u64).prop_map(|(exponent, offset, width)| {
+            let min = (10u64.pow(exponent)).saturating_sub(offset).max(1);
+            (min, min + width)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn invalid_pairs_match_brute_force(range in id_range()) {
+            let expected: Vec<u64> = (range.0..=range.1).filter(|&id| is_repeated(id, 2)).collect();
+
+            prop_assert_eq!(find_invalid_ids_for_repeats(&range, 2, Radix::DECIMAL), expected);
+        }
+
+        #[test]
+        fn invalid_ids_match_brute_force(range in id_range()) {
+            let expected: Vec<u64> = (range.0..=range.1)
+                .filter(|&id| (2..=id.to_string().len()).any(|repeats| is_repeated(id, repeats)))
+                .collect();
+
+            assert_contains_in_any_order(find_invalid_ids_for_range(&range, Radix::DECIMAL), expected);
+        }
+
+        #[test]
+        fn totals_match_enumeration(range in id_range()) {
+            let pairs = find_invalid_ids_for_repeats(&range, 2, Radix::DECIMAL);
+            prop_assert_eq!(
+                total_invalid_ids_for_repeats(&range, 2, Radix::DECIMAL).unwrap(),
+                InvalidIdTotals {
+                    count: pairs.len() as u64,
+                    sum: pairs.iter().sum()
+                }
+            );
+
+            let all = find_invalid_ids_for_range(&range, Radix::DECIMAL);
+            prop_assert_eq!(
+                total_invalid_ids_for_range(&range, Radix::DECIMAL).unwrap(),
+                InvalidIdTotals {
+                    count: all.len() as u64,
+                    sum: all.iter().sum()
+                }
+            );
+        }
+
+        #[test]
+        fn other_radixes_match_brute_force(
+            radix in (2..=36u32).prop_map(Radix::new),
+            exponent in 1..6u32,
+            offset in 0..500u64,
+            width in 0..1000u64,
+        ) {
+            let min = radix.0 as u64;
+            let min = min.pow(exponent).saturating_sub(offset).max(1);
+            let range = (min, min + width);
+            let expected: Vec<u64> = (range.0..=range.1)
+                .filter(|&id| is_invalid_in_radix(id, radix))
+                .collect();
+
+            assert_contains_in_any_order(find_invalid_ids_for_range(&range, radix), expected.clone());
+            prop_assert_eq!(
+                total_invalid_ids_for_range(&range, radix),
+                Ok(InvalidIdTotals {
+                    count: expected.len() as u64,
+                    sum: expected.iter().sum()
+                })
+            );
+        }
+
+        #[test]
+        fn point_queries_match_enumeration(
+            radix in (2..=36u32).prop_map(Radix::new),
+            id in 0..1_000_000u64,
+        ) {
+            let is_listed = find_invalid_ids_for_range(&(id, id), radix) == vec![id];
+            let repeating_unit = is_invalid(id, radix);
+            prop_assert_eq!(repeating_unit.is_some(), is_listed);
+            if let Some(RepeatingUnit { unit, repeats }) = repeating_unit {
+                prop_assert_eq!(
+                    unit.to_string_radix(radix).repeat(repeats as usize),
+                    id.to_string_radix(radix)
+                );
+                prop_assert!(!is_invalid_in_radix(unit, radix));
+            }
+
+            let next = next_invalid_id(id, radix).unwrap();
+            prop_assert_eq!(find_invalid_ids_for_range(&(id + 1, next), radix), vec![next]);
+
+            match previous_invalid_id(id, radix) {
+                Some(previous) => prop_assert_eq!(
+                    find_invalid_ids_for_range(&(previous, id - 1), radix),
+                    vec![previous]
+                ),
+                None => prop_assert!(find_invalid_ids_for_range(&(0, id.saturating_sub(1)), radix).is_empty()),
+            }
+        }
+
+        #[test]
+        fn explanations_match_enumeration(range in id_range()) {
+            for (id, units) in explain_invalid_ids_for_range(&range, Radix::DECIMAL) {
+                let repeats: Vec<u32> = (2..=range.1.digits(Radix::DECIMAL))
+                    .rev()
+                    .filter(|&repeats| {
+                        find_invalid_ids_for_repeats(&range, repeats, Radix::DECIMAL).contains(&id)
+                    })
+                    .collect();
+
+                prop_assert_eq!(
+                    units.iter().map(|unit| unit.repeats).collect::<Vec<_>>(),
+                    repeats
+                );
+                for RepeatingUnit { unit, repeats } in units {
+                    prop_assert_eq!(unit.to_string().repeat(repeats as usize), id.to_string());
+                }
+            }
+        }
+
+        #[test]
+        fn nth_invalid_id_matches_enumeration(
+            radix in (2..=36u32).prop_map(Radix::new),
+            max in 1..1_000_000u64,
+            position in any::<prop::sample::Index>(),
+        ) {
+            let ids: Vec<u64> = find_invalid_ids_for_range(&(0, max), radix).into_iter().sorted().collect();
+            prop_assume!(!ids.is_empty());
+            let n = position.index(ids.len());
+
+            prop_assert_eq!(nth_invalid_id(n as u64, radix), Some(ids[n]));
+            prop_assert_eq!(rank_invalid_id(ids[n], radix), n as u64);
+            prop_assert_eq!(count_invalid_ids_for_range(&(0, max), radix), ids.len() as u64);
+        }
+
+        #[test]
+        fn overlapping_ranges_are_counted_once(ranges in prop::collection::vec(id_range(), 1..6)) {
+            let ids: Vec<u64> = ranges
+                .iter()
+                .flat_map(|&(min, max)| min..=max)
+                .unique()
+                .filter(|&id| is_invalid_in_radix(id, Radix::DECIMAL))
+                .collect();
+
+            prop_assert_eq!(sum_invalid_ids(&ranges, Radix::DECIMAL), Ok(ids.iter().sum()));
+            prop_assert_eq!(
+                sum_invalid_id_pairs(&ranges, Radix::DECIMAL),
+                Ok(ids.iter().filter(|&&id| is_repeated(id, 2)).sum())
+            );
+        }
+
+        #[test]
+        fn totals_for_each_repeat_count_match_enumeration(range in id_range(), repeats in 2..=10u32) {
+            let ids = find_invalid_ids_for_repeats(&range, repeats, Radix::DECIMAL);
+
+            prop_assert_eq!(
+                total_invalid_ids_for_repeats(&range, repeats, Radix::DECIMAL).unwrap(),
+                InvalidIdTotals {
+                    count: ids.len() as u64,
+                    sum: ids.iter().sum()
+                }
+            );
+        }
     }
 }
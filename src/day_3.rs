@@ -6,31 +6,42 @@
 
 use std::fs;
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-3-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-3/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 3.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-3-input.txt").expect("Failed to read file");
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
 
-    let battery_banks = parse_input(&contents);
+    let (two_battery_joltage, twelve_battery_joltage) = solve(&contents);
 
     println!(
         "The total output joltage for 2 batteries is {}",
-        sum_highest_joltage(&battery_banks, 2)
+        two_battery_joltage
     );
 
     println!(
         "The total output joltage for 12 batteries is {}",
-        sum_highest_joltage(&battery_banks, 12)
+        twelve_battery_joltage
     );
 }
 
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    let battery_banks = parse_input(contents);
+
+    (
+        sum_highest_joltage(&battery_banks, 2).to_string(),
+        sum_highest_joltage(&battery_banks, 12).to_string(),
+    )
+}
+
 /// Represents a bank of batteries that combine to provide a joltage
 type BatteryBank = Vec<u32>;
 
 /// Parse input so that each line is a [`BatteryBank`], taking the digits as the individual batteries
-fn parse_input(input: &String) -> Vec<BatteryBank> {
+fn parse_input(input: &str) -> Vec<BatteryBank> {
     input
         .lines()
         .map(|line| {
@@ -62,7 +73,7 @@ fn find_highest_joltage(bank: &BatteryBank, digits: usize) -> u64 {
 }
 
 /// Solves both parts, part 1 `digits` = 2, part 2 `digits` = 12.
-fn sum_highest_joltage(banks: &Vec<BatteryBank>, digits: usize) -> u64 {
+fn sum_highest_joltage(banks: &[BatteryBank], digits: usize) -> u64 {
     banks
         .iter()
         .map(|bank| find_highest_joltage(bank, digits))
@@ -72,6 +83,8 @@ fn sum_highest_joltage(banks: &Vec<BatteryBank>, digits: usize) -> u64 {
 #[cfg(test)]
 mod tests {
     use crate::day_3::*;
+    use itertools::Itertools;
+    use proptest::prelude::*;
 
     fn example_banks() -> Vec<BatteryBank> {
         vec![
@@ -135,4 +148,30 @@ mod tests {
         assert_eq!(sum_highest_joltage(&example_banks(), 2), 357);
         assert_eq!(sum_highest_joltage(&example_banks(), 12), 3121910778619);
     }
+
+    /// The oracle for [`find_highest_joltage`], trying every choice of batteries
+    fn brute_force_highest_joltage(bank: &BatteryBank, digits: usize) -> u64 {
+        bank.iter()
+            .combinations(digits)
+            .map(|batteries| {
+                batteries
+                    .into_iter()
+                    .fold(0, |acc, &digit| acc * 10 + digit as u64)
+            })
+            .max()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn highest_joltage_matches_brute_force(
+            (bank, digits) in prop::collection::vec(1..=9u32, 1..12)
+                .prop_flat_map(|bank| { let len = bank.len(); (Just(bank), 1..=len) })
+        ) {
+            prop_assert_eq!(
+                find_highest_joltage(&bank, digits),
+                brute_force_highest_joltage(&bank, digits)
+            );
+        }
+    }
 }
@@ -1,6 +1,7 @@
 //! This is my solution for [Advent of Code - Day 4: _Printing Department_](https://adventofcode.com/2025/day/4)
 //!
-//! Today's solution is implemented by the [`PrintingDepartment`] struct, which holds the set of [`Roll`] locations.
+//! Today's solution is implemented by the [`PrintingDepartment`] struct, which holds a [`Grid`] marking where the rolls
+//! of paper are.
 //!
 //! - [`PrintingDepartment::neighbour_count`] is used to determine which rolls are accessible and removable
 //! - [`PrintingDepartment::count_accessible_rolls`] solves part one by counting the rolls with 3 or fewer
@@ -10,80 +11,66 @@
 //!   until the system is stable and no further rolls are accessible, returning the total count of rolls that could be
 //!   removed.
 
-use std::collections::HashSet;
+use crate::helpers::geometry::Point2;
+use crate::helpers::grid::Grid;
 use std::fs;
 
-/// represents the location of a roll of paper on the printing department floor
-#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
-struct Roll {
-    x: usize,
-    y: usize,
-}
-
-/// represents a printing department by the list of where rolls of paper are located
+/// represents a printing department by a grid of the floor, where `true` marks a roll of paper
 #[derive(Debug, Eq, PartialEq, Clone)]
 struct PrintingDepartment {
-    rolls: HashSet<Roll>,
+    floor: Grid<bool>,
 }
 
-impl From<&String> for PrintingDepartment {
+impl From<&str> for PrintingDepartment {
     /// Interpret the puzzle input as a grid representing the department floor. The origin (0,0) is in the top-left,
     /// The position of `@` symbols represent where the rolls of paper are located
-    fn from(value: &String) -> Self {
-        let rolls = value
-            .lines()
-            .enumerate()
-            .flat_map(move |(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter(|&(_, cell)| cell == '@')
-                    .map(move |(x, _)| Roll { x, y })
-            })
-            .collect();
-
-        PrintingDepartment { rolls }
+    fn from(value: &str) -> Self {
+        PrintingDepartment {
+            floor: Grid::parse(value, |cell| cell == '@'),
+        }
     }
 }
 
 impl PrintingDepartment {
     /// Given a location on the department floor, how many of the up to 8 adjacent locations have rolls of paper.
     /// The room's walls are counted as empty.
-    fn neighbour_count(&self, x: usize, y: usize) -> usize {
-        (y.checked_sub(1).unwrap_or(0)..=(y + 1))
-            .flat_map(|y1| {
-                (x.checked_sub(1).unwrap_or(0)..=(x + 1))
-                    .filter(move |&x1| x != x1 || y != y1)
-                    .map(move |x1| Roll { x: x1, y: y1 })
-                    .filter(|coord| self.rolls.contains(coord))
-            })
+    fn neighbour_count(&self, position: Point2<usize>) -> usize {
+        self.floor
+            .neighbours_8(position)
+            .filter(|&position| self.floor[position])
             .count()
     }
 
+    /// The locations of all the rolls of paper
+    fn rolls(&self) -> impl Iterator<Item = Point2<usize>> + '_ {
+        self.floor.positions(|&roll| roll)
+    }
+
+    fn roll_count(&self) -> usize {
+        self.rolls().count()
+    }
+
     /// Solves part one - How many of the rolls have less than four neighbours
     fn count_accessible_rolls(&self) -> usize {
-        self.rolls
-            .iter()
-            .filter(|roll| self.neighbour_count(roll.x, roll.y) < 4)
+        self.rolls()
+            .filter(|&roll| self.neighbour_count(roll) < 4)
             .count()
     }
 
     /// Returns a copy of the grid with [`accessible_rolls`](PrintingDepartment::count_accessible_rolls) removed.
     fn remove_accessible_rolls(&self) -> PrintingDepartment {
-        let rolls = self
-            .rolls
-            .iter()
-            .filter(|roll| self.neighbour_count(roll.x, roll.y) >= 4)
-            .cloned()
-            .collect();
+        let floor = Grid::from_fn(self.floor.width(), self.floor.height(), |position| {
+            self.floor[position] && self.neighbour_count(position) >= 4
+        });
 
-        PrintingDepartment { rolls }
+        PrintingDepartment { floor }
     }
 
     /// Solves part two - Remove rolls recursively until all rolls are unremovable, returning how many rolls could be
     /// removed
     fn count_removable_rolls(&self) -> usize {
         let next = self.remove_accessible_rolls();
-        let removed = self.rolls.len() - next.rolls.len();
+        let removed = self.roll_count() - next.roll_count();
 
         if removed == 0 {
             0
@@ -93,27 +80,43 @@ impl PrintingDepartment {
     }
 }
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-4-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-4/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 4.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-4-input.txt").expect("Failed to read file");
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
 
-    let dept = PrintingDepartment::from(&contents);
+    let (accessible, removable) = solve(&contents);
 
-    println!("{} rolls are accessible", dept.count_accessible_rolls());
+    println!("{} rolls are accessible", accessible);
 
-    println!("{} rolls can be removed", dept.count_removable_rolls());
+    println!("{} rolls can be removed", removable);
+}
+
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    let dept = PrintingDepartment::from(contents);
+
+    (
+        dept.count_accessible_rolls().to_string(),
+        dept.count_removable_rolls().to_string(),
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day_4::{PrintingDepartment, Roll};
+    use crate::day_4::PrintingDepartment;
+    use crate::generators::generate_input;
+    use crate::helpers::geometry::Point2;
+    use crate::helpers::grid::Grid;
+    use crate::helpers::test::assert_grid_eq;
+    use proptest::prelude::*;
 
     impl PrintingDepartment {
         fn has_roll_at(&self, x: usize, y: usize) -> bool {
-            self.rolls.contains(&Roll { x, y })
+            self.floor.get(Point2::new(x, y)) == Some(&true)
         }
     }
 
@@ -133,14 +136,14 @@ mod tests {
     }
 
     fn sample_dept() -> PrintingDepartment {
-        PrintingDepartment::from(&sample_input())
+        PrintingDepartment::from(sample_input().as_str())
     }
 
     #[test]
     fn can_parse_input() {
-        let dept = PrintingDepartment::from(&sample_input());
+        let dept = PrintingDepartment::from(sample_input().as_str());
 
-        assert_eq!(dept.rolls.len(), 71);
+        assert_eq!(dept.roll_count(), 71);
 
         assert!(dept.has_roll_at(2, 0));
         assert!(dept.has_roll_at(8, 9));
@@ -155,8 +158,8 @@ mod tests {
     fn can_count_filled_neighbours() {
         let dept = sample_dept();
 
-        assert_eq!(dept.neighbour_count(0, 0), 2);
-        assert_eq!(dept.neighbour_count(4, 4), 8);
+        assert_eq!(dept.neighbour_count(Point2::new(0, 0)), 2);
+        assert_eq!(dept.neighbour_count(Point2::new(4, 4)), 8);
     }
 
     #[test]
@@ -170,12 +173,105 @@ mod tests {
 
         let next_dept = dept.remove_accessible_rolls();
 
-        assert_eq!(next_dept.rolls.len(), 58);
+        assert_eq!(next_dept.roll_count(), 58);
         assert_eq!(next_dept.count_accessible_rolls(), 12);
+
+        let block = PrintingDepartment::from("@@@\n@@@\n@@@");
+        assert_grid_eq(
+            &block.remove_accessible_rolls().floor,
+            &Grid::parse(".@.\n@@@\n.@.", |cell| cell == '@'),
+        );
     }
 
     #[test]
     fn can_count_possible_removals() {
         assert_eq!(sample_dept().count_removable_rolls(), 43);
     }
+
+    /// The oracle for a roll's neighbours, checking each offset directly against the rows
+    fn brute_force_neighbours(rows: &[Vec<bool>], x: usize, y: usize) -> usize {
+        (-1..=1isize)
+            .flat_map(|dy| (-1..=1isize).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter(|&(dx, dy)| {
+                let (x1, y1) = (x as isize + dx, y as isize + dy);
+                y1 >= 0
+                    && x1 >= 0
+                    && rows
+                        .get(y1 as usize)
+                        .and_then(|row| row.get(x1 as usize))
+                        .is_some_and(|&roll| roll)
+            })
+            .count()
+    }
+
+    /// The rolls that are accessible, as `(x, y)` pairs
+    fn brute_force_accessible(rows: &[Vec<bool>]) -> Vec<(usize, usize)> {
+        (0..rows.len())
+            .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| rows[y][x] && brute_force_neighbours(rows, x, y) < 4)
+            .collect()
+    }
+
+    fn render(rows: &[Vec<bool>]) -> String {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&roll| if roll { '@' } else { '.' })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+
+    fn floor() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::bool::weighted(0.6), width),
+                height,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn accessible_rolls_match_brute_force(rows in floor()) {
+            let dept = PrintingDepartment::from(render(&rows).as_str());
+
+            prop_assert_eq!(dept.count_accessible_rolls(), brute_force_accessible(&rows).len());
+        }
+
+        #[test]
+        fn removable_rolls_match_brute_force(mut rows in floor()) {
+            let dept = PrintingDepartment::from(render(&rows).as_str());
+
+            let mut removed = 0;
+            loop {
+                let accessible = brute_force_accessible(&rows);
+                if accessible.is_empty() {
+                    break;
+                }
+                removed += accessible.len();
+                for (x, y) in accessible {
+                    rows[y][x] = false;
+                }
+            }
+
+            prop_assert_eq!(dept.count_removable_rolls(), removed);
+        }
+
+        #[test]
+        fn generated_floors_match_brute_force(seed in any::<u64>(), size in 1..40usize) {
+            let input = generate_input(4, size, seed).unwrap();
+            let rows: Vec<Vec<bool>> = input
+                .lines()
+                .map(|line| line.chars().map(|cell| cell == '@').collect())
+                .collect();
+
+            prop_assert_eq!(
+                PrintingDepartment::from(input.as_str()).count_accessible_rolls(),
+                brute_force_accessible(&rows).len()
+            );
+        }
+    }
 }
@@ -1,34 +1,41 @@
 //! This is my solution for [Advent of Code - Day 5: _Cafeteria_](https://adventofcode.com/2025/day/5)
 //!
-//! - [`parse_input`] delegates to [`parse_ranges`] and [`parse_ids`]. Notably both return sorted ids/ranges.
+//! - [`parse_input`] delegates to [`parse_ranges`] and [`parse_ids`]. The ranges are merged into an [`IntervalSet`].
 //! - [`count_fresh_ids`] solves part one
 //! - [`count_possible_fresh_ids`] solves part two
 
-use itertools::Itertools;
+use crate::helpers::ranges::IntervalSet;
 use std::fs;
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-5-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-5/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 5.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-5-input.txt").expect("Failed to read file");
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
 
-    let (ranges, ids) = parse_input(&contents);
+    let (fresh, possible_fresh) = solve(&contents);
 
-    println!("There are {} fresh IDs", count_fresh_ids(&ranges, &ids));
-    println!(
-        "There are {} possible fresh IDs",
-        count_possible_fresh_ids(&ranges)
-    );
+    println!("There are {} fresh IDs", fresh);
+    println!("There are {} possible fresh IDs", possible_fresh);
 }
 
-/// An inclusive range of ids that are fresh
-type IdRange = (u64, u64);
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    let (ranges, ids) = parse_input(contents);
 
-/// Parse each line in the format `{min}-{max}` as an [`IdRange`], and return them in sorted order (default sorting
-/// for pairs is by first entry, then by second)
-fn parse_ranges(input: &str) -> Vec<IdRange> {
+    (
+        count_fresh_ids(&ranges, &ids).to_string(),
+        count_possible_fresh_ids(&ranges).to_string(),
+    )
+}
+
+/// The ids that are fresh, merged from the inclusive ranges in the input
+type FreshIds = IntervalSet<u64>;
+
+/// Parse each line in the format `{min}-{max}` as an inclusive range, and merge them into a set of [`FreshIds`]
+fn parse_ranges(input: &str) -> FreshIds {
     input
         .lines()
         .map(|line| {
@@ -38,21 +45,16 @@ fn parse_ranges(input: &str) -> Vec<IdRange> {
 
             (start.parse().unwrap(), end.parse().unwrap())
         })
-        .sorted()
         .collect()
 }
 
-/// Parse each line as a numeric id, and return the ids in ascending order
+/// Parse each line as a numeric id
 fn parse_ids(input: &str) -> Vec<u64> {
-    input
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .sorted()
-        .collect()
+    input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
 /// The input is in two sections, split by a blank line. Delegate each section to a dedicated parser.
-fn parse_input(input: &String) -> (Vec<IdRange>, Vec<u64>) {
+fn parse_input(input: &str) -> (FreshIds, Vec<u64>) {
     let (range_input, id_input) = input
         .split_once("\n\n")
         .expect("Input should be two sections separated by a blank line");
@@ -60,58 +62,31 @@ fn parse_input(input: &String) -> (Vec<IdRange>, Vec<u64>) {
     (parse_ranges(range_input), parse_ids(id_input))
 }
 
-/// Assumes the input ranges and ids are sorted. Use a loop to iterate the ranges and ids in step. Counting where the
-/// current id is in the current range.
-fn count_fresh_ids(ranges: &Vec<IdRange>, ids: &Vec<u64>) -> u64 {
-    let mut fresh_count = 0;
-    let mut range_index = 0;
-    let mut id_index = 0;
-
-    loop {
-        if range_index == ranges.len() || id_index == ids.len() {
-            break;
-        }
-
-        let (min, max) = ranges[range_index];
-        let id = ids[id_index];
-
-        if id <= max {
-            id_index += 1;
-            if id >= min {
-                fresh_count += 1;
-            }
-        } else {
-            range_index += 1;
-        }
-    }
-
-    fresh_count
+/// Count the ids that are within one of the fresh ranges
+fn count_fresh_ids(fresh_ids: &FreshIds, ids: &[u64]) -> usize {
+    ids.iter().filter(|&&id| fresh_ids.contains(id)).count()
 }
 
-/// Assumes the passed ranges are sorted. Return the count of ids included within one of the ranges
-fn count_possible_fresh_ids(ranges: &Vec<IdRange>) -> u64 {
-    let mut total_ids = 0;
-    let mut id_threshold = 0;
-
-    for &(min, max) in ranges {
-        let lower_bound = min.max(id_threshold);
-        if lower_bound <= max {
-            total_ids += max - lower_bound + 1;
-        }
-        id_threshold = id_threshold.max(max + 1)
-    }
-
-    total_ids
+/// Return the count of ids included within one of the ranges. There are at most 2^64 `u64` ids, so this always fits in
+/// the `u128`.
+fn count_possible_fresh_ids(fresh_ids: &FreshIds) -> u128 {
+    fresh_ids
+        .covered_count()
+        .expect("A count of u64 ids always fits in a u128")
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_5::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
 
-    fn sample_data() -> (Vec<IdRange>, Vec<u64>) {
+    fn sample_data() -> (FreshIds, Vec<u64>) {
         (
-            vec![(3, 5), (10, 14), (12, 18), (16, 20)],
-            vec![1, 5, 8, 11, 17, 32],
+            vec![(3, 5), (10, 14), (12, 18), (16, 20)]
+                .into_iter()
+                .collect(),
+            vec![1, 8, 17, 11, 5, 32],
         )
     }
 
@@ -133,6 +108,10 @@ mod tests {
         .to_string();
 
         assert_eq!(parse_input(&input), sample_data());
+        assert_eq!(
+            sample_data().0.iter().collect::<Vec<_>>(),
+            vec![(3, 5), (10, 20)]
+        );
     }
 
     #[test]
@@ -146,4 +125,48 @@ mod tests {
         let (ranges, _) = sample_data();
         assert_eq!(count_possible_fresh_ids(&ranges), 14);
     }
+
+    /// Small ranges in a small space of ids, so they often overlap and share endpoints
+    fn ranges() -> impl Strategy<Value = Vec<(u64, u64)>> {
+        prop::collection::vec((0..200u64, 0..30u64), 1..20).prop_map(|ranges| {
+            ranges
+                .into_iter()
+                .map(|(min, width)| (min, min + width))
+                .collect()
+        })
+    }
+
+    fn render(ranges: &[(u64, u64)], ids: &[u64]) -> String {
+        let ranges: String = ranges
+            .iter()
+            .map(|(min, max)| format!("{}-{}\n", min, max))
+            .collect();
+        let ids: String = ids.iter().map(|id| format!("{}\n", id)).collect();
+
+        format!("{}\n{}", ranges, ids)
+    }
+
+    proptest! {
+        #[test]
+        fn fresh_ids_match_brute_force(
+            ranges in ranges(),
+            ids in prop::collection::vec(0..250u64, 1..30)
+        ) {
+            let (fresh_ids, parsed_ids) = parse_input(&render(&ranges, &ids));
+            let expected = ids
+                .iter()
+                .filter(|&&id| ranges.iter().any(|&(min, max)| (min..=max).contains(&id)))
+                .count();
+
+            prop_assert_eq!(count_fresh_ids(&fresh_ids, &parsed_ids), expected);
+        }
+
+        #[test]
+        fn possible_fresh_ids_match_brute_force(ranges in ranges()) {
+            let fresh_ids: FreshIds = ranges.iter().copied().collect();
+            let all_ids: HashSet<u64> = ranges.iter().flat_map(|&(min, max)| min..=max).collect();
+
+            prop_assert_eq!(count_possible_fresh_ids(&fresh_ids), all_ids.len() as u128);
+        }
+    }
 }
@@ -9,24 +9,32 @@
 use crate::day_6::Expression::*;
 use std::fs;
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-6-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-6/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 6.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-6-input.txt").expect("Failed to read file");
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
 
-    println!(
-        "The total of all expressions is {}",
-        sum_results(&parse_input(&contents))
-    );
+    let (total, cephalopod_total) = solve(&contents);
+
+    println!("The total of all expressions is {}", total);
 
     println!(
         "The total of all cephalopod expressions is {}",
-        sum_results(&parse_cephalopod_maths(&contents))
+        cephalopod_total
     );
 }
 
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    (
+        sum_results(&parse_input(contents)).to_string(),
+        sum_results(&parse_cephalopod_maths(contents)).to_string(),
+    )
+}
+
 /// Describes an expression as the operator and the list of parameters
 #[derive(Debug, Eq, PartialEq)]
 enum Expression {
@@ -46,7 +54,7 @@ impl Expression {
 
 /// Parse input as a columns of left to right numbers with the operator on the final row, each column representing an
 /// expression
-fn parse_input(input: &String) -> Vec<Expression> {
+fn parse_input(input: &str) -> Vec<Expression> {
     let mut rows: Vec<Vec<&str>> = input
         .trim()
         .lines()
@@ -72,7 +80,7 @@ fn parse_input(input: &String) -> Vec<Expression> {
 }
 
 /// Parse the input as blocks of columnar numbers, with most significant digits at the top, missing digits are ignored
-fn parse_cephalopod_maths(input: &String) -> Vec<Expression> {
+fn parse_cephalopod_maths(input: &str) -> Vec<Expression> {
     let mut rows: Vec<Vec<char>> = input
         .trim_end_matches("\n")
         .lines()
@@ -86,14 +94,15 @@ fn parse_cephalopod_maths(input: &String) -> Vec<Expression> {
     let mut expressions = Vec::new();
 
     for (idx, op) in ops.iter().enumerate().rev() {
-        let num = rows.iter().fold(0, |acc, row| {
+        // Columns without any digits separate the blocks. A column of zeros is still a number.
+        let num = rows.iter().fold(None, |acc: Option<u64>, row| {
             match row.get(idx).unwrap_or(&' ').to_digit(10) {
-                Some(digit) => acc * 10 + digit as u64,
+                Some(digit) => Some(acc.unwrap_or(0) * 10 + digit as u64),
                 None => acc,
             }
         });
 
-        if num > 0 {
+        if let Some(num) = num {
             current_nums.push(num)
         }
 
@@ -114,13 +123,14 @@ fn parse_cephalopod_maths(input: &String) -> Vec<Expression> {
 }
 
 /// Reduce the parsed expressions into the puzzle solution
-fn sum_results(expressions: &Vec<Expression>) -> u64 {
+fn sum_results(expressions: &[Expression]) -> u64 {
     expressions.iter().map(Expression::result).sum()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_6::*;
+    use proptest::prelude::*;
 
     fn example_input() -> String {
         "\
@@ -162,6 +172,14 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_parse_cephalopod_columns_of_zeros() {
+        assert_eq!(
+            parse_cephalopod_maths("2600\n*   \n"),
+            vec![Mul(vec![0, 0, 6, 2])]
+        );
+    }
+
     #[test]
     fn can_calculate_results() {
         assert_eq!(Mul(vec![123, 45, 6]).result(), 33210);
@@ -175,4 +193,111 @@ mod tests {
         assert_eq!(sum_results(&sample_expressions()), 4277556);
         assert_eq!(sum_results(&sample_cephalopod_expressions()), 3263827);
     }
+
+    /// Lay out columns of numbers as a worksheet, with each number aligned within its column as given, and return the
+    /// worksheet along with each column's expression
+    fn render(columns: &[(bool, Vec<(u64, bool)>)]) -> String {
+        let width = |column: &Vec<(u64, bool)>| {
+            column
+                .iter()
+                .map(|(num, _)| num.to_string().len())
+                .max()
+                .unwrap()
+        };
+        let rows = columns[0].1.len();
+
+        let mut lines: Vec<String> = (0..rows)
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|(_, column)| {
+                        let (num, left_aligned) = column[row];
+                        if left_aligned {
+                            format!("{:<1$}", num, width(column))
+                        } else {
+                            format!("{:>1$}", num, width(column))
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+
+        lines.push(
+            columns
+                .iter()
+                .map(|(is_add, column)| {
+                    format!("{:<1$}", if *is_add { "+" } else { "*" }, width(column))
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+
+        lines.join("\n") + "\n"
+    }
+
+    fn worksheet() -> impl Strategy<Value = Vec<(bool, Vec<(u64, bool)>)>> {
+        (1..5usize).prop_flat_map(|rows| {
+            prop::collection::vec(
+                (
+                    any::<bool>(),
+                    prop::collection::vec((1..10_000u64, any::<bool>()), rows),
+                ),
+                1..6,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn can_parse_any_worksheet(columns in worksheet()) {
+            let expected: Vec<Expression> = columns
+                .iter()
+                .map(|(is_add, column)| {
+                    let nums = column.iter().map(|&(num, _)| num).collect();
+                    if *is_add { Add(nums) } else { Mul(nums) }
+                })
+                .collect();
+            let expected_total: u64 = columns
+                .iter()
+                .map(|(is_add, column)| {
+                    let nums = column.iter().map(|&(num, _)| num);
+                    if *is_add { nums.sum::<u64>() } else { nums.product() }
+                })
+                .sum();
+
+            let expressions = parse_input(&render(&columns));
+            prop_assert_eq!(&expressions, &expected);
+            prop_assert_eq!(sum_results(&expressions), expected_total);
+        }
+
+        #[test]
+        fn can_parse_any_cephalopod_worksheet(columns in worksheet()) {
+            let worksheet = render(&columns);
+            let rows: Vec<Vec<char>> = worksheet.lines().map(|line| line.chars().collect()).collect();
+            let (digit_rows, operator_row) = rows.split_at(rows.len() - 1);
+
+            // Read each character column top to bottom as a number, working right to left through the blocks
+            let mut expected = Vec::new();
+            let mut start = 0;
+            for (is_add, column) in &columns {
+                let width = column.iter().map(|(num, _)| num.to_string().len()).max().unwrap();
+                let nums: Vec<u64> = (start..start + width)
+                    .rev()
+                    .map(|x| {
+                        digit_rows
+                            .iter()
+                            .filter_map(|row| row[x].to_digit(10))
+                            .fold(0, |acc, digit| acc * 10 + digit as u64)
+                    })
+                    .collect();
+                prop_assert_eq!(operator_row[0][start] == '+', *is_add);
+                expected.push(if *is_add { Add(nums) } else { Mul(nums) });
+                start += width + 1;
+            }
+            expected.reverse();
+
+            prop_assert_eq!(parse_cephalopod_maths(&worksheet), expected);
+        }
+    }
 }
@@ -1,21 +1,26 @@
 //! This is my solution for [Advent of Code - Day 7: _Laboratories_](https://adventofcode.com/2025/day/7)
 //!
 //! [`TachyonManifold`] encapsulates today's puzzle
-//! - [`TachyonManifold::from<&String>`] parses the puzzle input
+//! - [`TachyonManifold::from<&str>`] parses the puzzle input
 //! - [`TachyonManifold::count_splits] solves part one
-//! - [`TachyonManifold::count_paths] solves part two
+//! - [`TachyonManifold::count_paths] solves part two, counting the paths from each position below the source. The
+//!   counts are [`Memoised`] as the paths rejoin, filling the cache from the bottom row up.
 
+use crate::helpers::geometry::{Direction, Point2};
+use crate::helpers::grid::Grid;
+use crate::helpers::memo::{CacheStats, Memoised};
 use std::collections::HashSet;
 use std::fs;
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-7-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-7/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 7.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-7-input.txt").expect("Failed to read file");
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
 
-    let manifold = TachyonManifold::from(&contents);
+    let manifold = TachyonManifold::from(contents.as_str());
 
     println!(
         "The tachyon beam was split {} times",
@@ -28,97 +33,135 @@ pub fn run() {
     );
 }
 
-/// Represents a location within a [`TachyonManifold`]
-type Coordinate = (usize, usize);
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    let manifold = TachyonManifold::from(contents);
 
-/// Represents a manifold by the location of the beam source,
-/// the locations of the beam splitters, and its dimensions.
+    (
+        manifold.count_splits().to_string(),
+        manifold.count_paths().to_string(),
+    )
+}
+
+/// What occupies a location within a [`TachyonManifold`]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Cell {
+    Empty,
+    Source,
+    Splitter,
+}
+
+/// Represents a manifold by the location of the beam source, and a grid of what occupies each location.
 #[derive(Debug, Eq, PartialEq)]
 struct TachyonManifold {
-    source: Coordinate,
-    splitters: HashSet<Coordinate>,
-    width: usize,
-    height: usize,
+    source: Point2<usize>,
+    cells: Grid<Cell>,
 }
 
-impl From<&String> for TachyonManifold {
+impl From<&str> for TachyonManifold {
     /// Interpret the input as a 2D grid, with `S` representing the
     /// beam source, and `^` representing the location of a beam
     /// splitter
-    fn from(input: &String) -> TachyonManifold {
-        let mut start = None;
-        let mut splitters = HashSet::new();
-        let mut width = 0;
-        let mut height = 0;
-
-        for (y, line) in input.lines().enumerate() {
-            for (x, char) in line.chars().enumerate() {
-                match char {
-                    'S' => start = Some((x, y)),
-                    '^' => {
-                        splitters.insert((x, y));
-                    }
-                    _ => {}
-                }
-            }
-            width = line.len();
-            height += 1;
-        }
+    fn from(input: &str) -> TachyonManifold {
+        let cells = Grid::parse(input, |char| match char {
+            'S' => Cell::Source,
+            '^' => Cell::Splitter,
+            _ => Cell::Empty,
+        });
 
-        TachyonManifold {
-            source: start.expect("Thin input should include a start position"),
-            splitters,
-            width,
-            height,
-        }
+        let source = cells
+            .positions(|&cell| cell == Cell::Source)
+            .next()
+            .expect("Thin input should include a start position");
+
+        TachyonManifold { source, cells }
     }
 }
 
 impl TachyonManifold {
+    fn is_splitter(&self, position: Point2<usize>) -> bool {
+        self.cells.get(position) == Some(&Cell::Splitter)
+    }
+
+    /// The columns either side of a splitter that the split beams continue in, or `None` for a beam that leaves
+    /// through the side of the manifold
+    fn split_beams(&self, splitter: Point2<usize>) -> [Option<usize>; 2] {
+        [Direction::Left, Direction::Right].map(|side| {
+            splitter
+                .checked_step(side)
+                .filter(|&beam| self.cells.contains(beam))
+                .map(|beam| beam.x)
+        })
+    }
+
     /// Count the number of times a beam is split by a splitter as it passes through
     /// the manifold.
     fn count_splits(&self) -> usize {
         let mut splits = 0;
-        let (initial_beam, start_row) = self.source;
-        let mut beams: HashSet<usize> = vec![initial_beam].into_iter().collect();
-
-        for y in start_row..self.height {
-            for x in beams.clone() {
-                if self.splitters.contains(&(x, y)) {
-                    beams.remove(&x);
-                    beams.insert(x - 1);
-                    beams.insert(x + 1);
+        let mut beams: HashSet<usize> = vec![self.source.x].into_iter().collect();
+
+        // Each row's beams are built from the previous row's, so a beam split into the column of another splitter
+        // in the same row continues down, rather than being split again or removed by that splitter
+        for y in self.source.y..self.cells.height() {
+            let mut next_beams = HashSet::new();
+            for x in beams {
+                let position = Point2::new(x, y);
+                if self.is_splitter(position) {
+                    next_beams.extend(self.split_beams(position).into_iter().flatten());
                     splits += 1
+                } else {
+                    next_beams.insert(x);
                 }
             }
+            beams = next_beams;
         }
 
         splits
     }
 
-    /// Count the number of possible routes a beam can take through the manifold
+    /// Count the number of possible routes a beam can take through the manifold. The number of paths from a position
+    /// is the number from the position below, or the sum of those from either side if that is a splitter. A beam split
+    /// out through the side of the manifold has left it, so counts as a complete path.
     fn count_paths(&self) -> usize {
-        let (initial_beam, start_row) = self.source;
-        let mut beams: Vec<usize> = vec![0; self.width + 1].into_iter().collect();
-        beams[initial_beam] = 1;
-
-        for y in start_row..self.height {
-            for (x, paths) in beams.clone().into_iter().enumerate() {
-                if self.splitters.contains(&(x, y)) {
-                    beams[x] = 0;
-                    beams[x - 1] += paths;
-                    beams[x + 1] += paths;
+        self.count_paths_with_stats().0
+    }
+
+    /// [`TachyonManifold::count_paths`], also reporting how the cache performed. The cache is filled from the bottom
+    /// row up, so each lookup only recurses into the row below, which is already cached, however tall the manifold.
+    fn count_paths_with_stats(&self) -> (usize, CacheStats) {
+        let paths_from = Memoised::new(
+            |paths_from: &dyn Fn(Point2<usize>) -> usize, beam: Point2<usize>| {
+                let below = beam
+                    .checked_step(Direction::Down)
+                    .filter(|&below| self.cells.contains(below));
+
+                match below {
+                    None => 1,
+                    Some(splitter) if self.is_splitter(splitter) => self
+                        .split_beams(splitter)
+                        .into_iter()
+                        .map(|beam| beam.map_or(1, |x| paths_from(Point2::new(x, splitter.y))))
+                        .sum(),
+                    Some(below) => paths_from(below),
                 }
+            },
+        );
+
+        for y in (self.source.y + 1..self.cells.height()).rev() {
+            for x in 0..self.cells.width() {
+                paths_from.get(Point2::new(x, y));
             }
         }
 
-        beams.iter().sum()
+        (paths_from.get(self.source), paths_from.stats())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_7::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
 
     fn sample_input() -> String {
         "\
@@ -143,30 +186,143 @@ mod tests {
 
     #[test]
     fn can_parse_input() {
-        let manifold = TachyonManifold::from(&sample_input());
+        let manifold = TachyonManifold::from(sample_input().as_str());
 
-        assert_eq!(manifold.source, (7, 0));
+        assert_eq!(manifold.source, Point2::new(7, 0));
 
-        assert_eq!(manifold.splitters.len(), 22);
-        assert!(manifold.splitters.contains(&(7, 2)));
-        assert!(manifold.splitters.contains(&(6, 4)));
-        assert!(manifold.splitters.contains(&(8, 4)));
+        assert_eq!(
+            manifold
+                .cells
+                .positions(|&cell| cell == Cell::Splitter)
+                .count(),
+            22
+        );
+        assert!(manifold.is_splitter(Point2::new(7, 2)));
+        assert!(manifold.is_splitter(Point2::new(6, 4)));
+        assert!(manifold.is_splitter(Point2::new(8, 4)));
 
-        assert_eq!(manifold.width, 15);
-        assert_eq!(manifold.height, 16);
+        assert_eq!(manifold.cells.width(), 15);
+        assert_eq!(manifold.cells.height(), 16);
+    }
+
+    #[test]
+    fn can_split_beams_at_the_edges() {
+        let manifold = TachyonManifold::from("S.\n^.\n.^");
+
+        // The beams split out to the left of the first splitter and the right of the second have each left the
+        // manifold, but are still paths
+        assert_eq!(manifold.count_splits(), 2);
+        assert_eq!(manifold.count_paths(), 3);
+    }
+
+    #[test]
+    fn can_split_beams_from_adjacent_splitters() {
+        // The beams in columns 1 and 2 both reach the pair of splitters on the fourth row, and each is split into
+        // the other's column, so both beams reach the pair below too
+        let manifold = TachyonManifold::from("..S...\n..^...\n...^..\n.^^...\n.^^...");
+
+        assert_eq!(manifold.count_splits(), 6);
     }
 
     #[test]
     fn can_split_beams() {
-        let manifold = TachyonManifold::from(&sample_input());
+        let manifold = TachyonManifold::from(sample_input().as_str());
 
         assert_eq!(manifold.count_splits(), 21);
     }
 
     #[test]
     fn can_count_possible_paths() {
-        let manifold = TachyonManifold::from(&sample_input());
+        let manifold = TachyonManifold::from(sample_input().as_str());
 
         assert_eq!(manifold.count_paths(), 40);
     }
+
+    #[test]
+    fn can_fill_path_cache_once() {
+        let manifold = TachyonManifold::from(sample_input().as_str());
+        let (paths, stats) = manifold.count_paths_with_stats();
+
+        // Each cell of the 15 rows below the source is computed once, then the source itself. Every lookup of the row
+        // below is then a hit, one for each cell above the bottom row, and a second for each of the 22 splitters.
+        assert_eq!(paths, 40);
+        assert_eq!(stats.misses, 15 * 15 + 1);
+        assert_eq!(stats.size, 15 * 15 + 1);
+        assert_eq!(stats.hits, 15 * 14 + 22 + 1);
+    }
+
+    #[test]
+    fn can_count_paths_through_a_tall_manifold() {
+        // Deep enough that following each beam down recursively would overflow the stack
+        let mut input = ".S.\n".to_string();
+        input.push_str(&".^.\n...\n".repeat(50_000));
+        let manifold = TachyonManifold::from(input.as_str());
+
+        assert_eq!(manifold.count_paths(), 2);
+    }
+
+    /// The oracle for both parts, following every path a beam can take one at a time. Returns the number of paths,
+    /// adding every splitter reached to `splitters`.
+    fn follow_beam(
+        rows: &[Vec<char>],
+        (x, y): (usize, usize),
+        splitters: &mut HashSet<(usize, usize)>,
+    ) -> usize {
+        match rows.get(y + 1).map(|row| row[x]) {
+            None => 1,
+            Some('^') => {
+                splitters.insert((x, y + 1));
+                [x.checked_sub(1), Some(x + 1)]
+                    .into_iter()
+                    .map(|side| match side.filter(|&side| side < rows[0].len()) {
+                        Some(side) => follow_beam(rows, (side, y + 1), splitters),
+                        // The beam has left through the side of the manifold, which ends the path
+                        None => 1,
+                    })
+                    .sum()
+            }
+            Some(_) => follow_beam(rows, (x, y + 1), splitters),
+        }
+    }
+
+    /// Manifolds with the source on the top row, and splitters anywhere below
+    fn manifold_rows() -> impl Strategy<Value = Vec<Vec<char>>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            (
+                0..width,
+                prop::collection::vec(
+                    prop::collection::vec(prop::bool::weighted(0.3), width),
+                    height,
+                ),
+            )
+                .prop_map(move |(source, splitters)| {
+                    let mut rows = vec![
+                        (0..width)
+                            .map(|x| if x == source { 'S' } else { '.' })
+                            .collect::<Vec<_>>(),
+                    ];
+                    rows.extend(splitters.into_iter().map(|row| {
+                        row.into_iter()
+                            .map(|splitter| if splitter { '^' } else { '.' })
+                            .collect()
+                    }));
+                    rows
+                })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn splits_and_paths_match_brute_force(rows in manifold_rows()) {
+            let input = rows.iter().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>().join("\n");
+            let manifold = TachyonManifold::from(input.as_str());
+
+            let source = rows[0].iter().position(|&cell| cell == 'S').unwrap();
+            let mut splitters = HashSet::new();
+            let paths = follow_beam(&rows, (source, 0), &mut splitters);
+
+            prop_assert_eq!(manifold.count_splits(), splitters.len());
+            prop_assert_eq!(manifold.count_paths(), paths);
+        }
+    }
 }
@@ -5,7 +5,7 @@
 //! boxes in a [`DecorationProject`].
 //!
 //! - [`circuits_after_n_connections`] does the bulk of the work for part one, clustering the boxes into circuits using
-//!   thr first `n` connections
+//!   thr first `n` connections. Circuits are tracked with a [`UnionFind`], so merging them is cheap.
 //! - [`product_of_3_largest_circuits_after_n_connections`] wraps [`circuits_after_n_connections`] to produce the puzzle
 //!   solution
 //! - [`find_final_connection`] does most of the work for part two, merging the circuits until there is only one, then
@@ -13,36 +13,50 @@
 //! - [`find_x_product_of_final_connection`] calculates the puzzle solution from the connection provided by
 //!   [`find_final_connection`]
 
+use crate::helpers::geometry::Point3;
+use crate::helpers::union_find::UnionFind;
 use itertools::Itertools;
 use std::fs;
 
-/// The entry point for running the solutions with the 'real' puzzle input.
+/// The entry point for running the solutions with a puzzle input.
 ///
-/// - The puzzle input is expected to be at `<project_root>/res/day-8-input`
+/// - The 'real' puzzle input is expected to be at `<project_root>/res/day-8/default.txt`, but `input_path` can point
+///   at another profile's input instead
 /// - It is expected this will be called by [`super::main()`] when the user elects to run day 8.
-pub fn run() {
-    let contents = fs::read_to_string("res/day-8-input.txt").expect("Failed to read file");
+pub fn run(input_path: &str) {
+    let contents = fs::read_to_string(input_path).expect("Failed to read file");
 
-    let project = parse_input(&contents);
+    let (largest_circuits_product, final_connection_product) = solve(&contents);
 
     println!(
         "After 1000 connections the product of the three largest is {}",
-        product_of_3_largest_circuits_after_n_connections(&project, 1000)
+        largest_circuits_product
     );
 
     println!(
         "The product of x-coordinates of the final connection is {}",
-        find_x_product_of_final_connection(&project)
+        final_connection_product
     );
 }
 
+/// Solves both parts for a given puzzle input, returning the answers as they would be submitted.
+pub fn solve(contents: &str) -> (String, String) {
+    let project = parse_input(contents);
+
+    (
+        product_of_3_largest_circuits_after_n_connections(&project, 1000).to_string(),
+        find_x_product_of_final_connection(&project).to_string(),
+    )
+}
+
 /// The location of a junction box in 3D space
-type JunctionBox = (i64, i64, i64);
+type JunctionBox = Point3<i64>;
 
 /// A connection between two [`JunctionBox`]es represented by the index of each in the list of junction boxes
 type Connection = (usize, usize);
 
 /// Combines the junction boxes with all their possible connections, sorted shortest first
+#[derive(Debug)]
 struct DecorationProject {
     boxes: Vec<JunctionBox>,
     connections: Vec<Connection>,
@@ -50,8 +64,8 @@ struct DecorationProject {
 
 /// Parses each line as a [`JunctionBox`] in the format `{x},{y},{z}`. Delegates to [`order_possible_connections`]
 /// to build the connections once here so I don't have to repeat that expensive operation for both parts.
-fn parse_input(input: &String) -> DecorationProject {
-    let boxes = input
+fn parse_input(input: &str) -> DecorationProject {
+    let boxes: Vec<JunctionBox> = input
         .lines()
         .map(|line| {
             let (x, y, z) = line
@@ -61,7 +75,7 @@ fn parse_input(input: &String) -> DecorationProject {
                 .next()
                 .unwrap();
 
-            (x, y, z)
+            Point3::new(x, y, z)
         })
         .collect();
 
@@ -70,14 +84,14 @@ fn parse_input(input: &String) -> DecorationProject {
     DecorationProject { boxes, connections }
 }
 
-/// The Euclidean distance between the two boxes in 3D space
-fn connection_distance((x_a, y_a, z_a): &JunctionBox, (x_b, y_b, z_b): &JunctionBox) -> i64 {
-    (x_a - x_b).pow(2) + (y_a - y_b).pow(2) + (z_a - z_b).pow(2)
+/// The Euclidean distance between the two boxes in 3D space. This is left squared, as it's only used for ordering.
+fn connection_distance(a: &JunctionBox, b: &JunctionBox) -> i64 {
+    a.squared_euclidean_distance(b)
 }
 
 /// Calculate all the possible connections between boxes, and return these sorted shortest first, represented by a pair
 /// of indices into the list of boxes
-fn order_possible_connections(junction_boxes: &Vec<JunctionBox>) -> Vec<Connection> {
+fn order_possible_connections(junction_boxes: &[JunctionBox]) -> Vec<Connection> {
     junction_boxes
         .iter()
         .enumerate()
@@ -93,27 +107,13 @@ fn circuits_after_n_connections(
     project: &DecorationProject,
     target_connections: usize,
 ) -> Vec<usize> {
-    let mut circuits: Vec<usize> = project
-        .boxes
-        .iter()
-        .enumerate()
-        .map(|(idx, _)| idx)
-        .collect();
+    let mut circuits = UnionFind::new(project.boxes.len());
 
     for &(a, b) in project.connections.iter().take(target_connections) {
-        let circuit_a = circuits[a];
-        let circuit_b = circuits[b];
-
-        if circuit_a != circuit_b {
-            circuits.iter_mut().for_each(|circuit_id| {
-                if circuit_id == &circuit_b {
-                    *circuit_id = circuit_a
-                }
-            });
-        }
+        circuits.union(a, b);
     }
 
-    circuits.into_iter().counts().values().cloned().collect()
+    circuits.component_sizes()
 }
 
 /// Use [`circuits_after_n_connections`] to find the circuit sizes. The puzzle solution is then the product of the
@@ -133,26 +133,10 @@ fn product_of_3_largest_circuits_after_n_connections(
 /// The bulk of the work for part two, combine junction boxes into a single circuit, combining by the smallest
 /// connections until all junction boxes are included. Returns that final connection.
 fn find_final_connection(project: &DecorationProject) -> Connection {
-    let mut circuits: Vec<usize> = project
-        .boxes
-        .iter()
-        .enumerate()
-        .map(|(idx, _)| idx)
-        .collect();
+    let mut circuits = UnionFind::new(project.boxes.len());
 
     for &(a, b) in project.connections.iter() {
-        let circuit_a = circuits[a];
-        let circuit_b = circuits[b];
-
-        if circuit_a != circuit_b {
-            circuits.iter_mut().for_each(|circuit_id| {
-                if circuit_id == &circuit_b {
-                    *circuit_id = circuit_a
-                }
-            });
-        }
-
-        if circuits.iter().unique().count() == 1 {
+        if circuits.union(a, b) && circuits.component_count() == 1 {
             return (a, b);
         }
     }
@@ -164,12 +148,15 @@ fn find_final_connection(project: &DecorationProject) -> Connection {
 fn find_x_product_of_final_connection(project: &DecorationProject) -> i64 {
     let (a, b) = find_final_connection(project);
 
-    project.boxes[a].0 * project.boxes[b].0
+    project.boxes[a].x * project.boxes[b].x
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_8::*;
+    use crate::generators::generate_input;
+    use crate::helpers::test::assert_contains_in_any_order;
+    use proptest::prelude::*;
 
     fn sample_input() -> String {
         "\
@@ -219,6 +206,9 @@ mod tests {
             (984, 92, 344),
             (425, 690, 689),
         ]
+        .into_iter()
+        .map(Point3::from)
+        .collect()
     }
 
     fn sample_project() -> DecorationProject {
@@ -247,9 +237,9 @@ mod tests {
 
     #[test]
     fn can_merge_circuits() {
-        assert_eq!(
-            circuits_after_n_connections(&sample_project(), 10).len(),
-            11
+        assert_contains_in_any_order(
+            circuits_after_n_connections(&sample_project(), 10),
+            [5, 4, 2, 2, 1, 1, 1, 1, 1, 1, 1],
         );
 
         assert_eq!(
@@ -263,4 +253,82 @@ mod tests {
         assert_eq!(find_final_connection(&sample_project()), (10, 12));
         assert_eq!(find_x_product_of_final_connection(&sample_project()), 25272);
     }
+
+    /// The oracle for the circuits, finding the connected boxes by searching out from each box in turn
+    fn brute_force_circuits(box_count: usize, connections: &[Connection]) -> Vec<usize> {
+        let mut visited = vec![false; box_count];
+        let mut sizes = Vec::new();
+
+        for start in 0..box_count {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut stack = vec![start];
+            let mut size = 0;
+            while let Some(current) = stack.pop() {
+                size += 1;
+                for &(a, b) in connections {
+                    for (from, to) in [(a, b), (b, a)] {
+                        if from == current && !visited[to] {
+                            visited[to] = true;
+                            stack.push(to);
+                        }
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+
+        sizes
+    }
+
+    fn project() -> impl Strategy<Value = DecorationProject> {
+        prop::collection::vec((0..100i64, 0..100i64, 0..100i64), 2..15).prop_map(|boxes| {
+            let boxes: Vec<JunctionBox> = boxes.into_iter().map(Point3::from).collect();
+            let connections = order_possible_connections(&boxes);
+
+            DecorationProject { boxes, connections }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn circuits_match_brute_force(project in project(), connection_count in 0..100usize) {
+            let connection_count = connection_count.min(project.connections.len());
+
+            assert_contains_in_any_order(
+                circuits_after_n_connections(&project, connection_count),
+                brute_force_circuits(
+                    project.boxes.len(),
+                    &project.connections[..connection_count],
+                ),
+            );
+        }
+
+        #[test]
+        fn final_connection_matches_brute_force(project in project()) {
+            let connection_count = (1..=project.connections.len())
+                .find(|&count| {
+                    brute_force_circuits(project.boxes.len(), &project.connections[..count]).len() == 1
+                })
+                .unwrap();
+
+            prop_assert_eq!(
+                find_final_connection(&project),
+                project.connections[connection_count - 1]
+            );
+        }
+
+        #[test]
+        fn generated_circuits_match_brute_force(seed in any::<u64>(), size in 2..30usize) {
+            let project = parse_input(&generate_input(8, size, seed).unwrap());
+            let connection_count = size.min(project.connections.len());
+
+            assert_contains_in_any_order(
+                circuits_after_n_connections(&project, connection_count),
+                brute_force_circuits(size, &project.connections[..connection_count]),
+            );
+        }
+    }
 }
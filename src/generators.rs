@@ -0,0 +1,392 @@
+//! Random puzzle inputs for each day, for stress testing the solutions beyond the one real input we each have.
+//!
+//! Each generator produces an input in the same format as the real puzzle input, scaled by a `size` whose meaning
+//! depends on the day (e.g. the number of instructions for day 1, or the side of the grid for day 4). Inputs are
+//! generated from a seed, so a failing input can be reproduced.
+//!
+//! - [`generate_input`] generates an input for a day
+//! - [`write_generated_input`] is the entry point for the `generate` command, writing an input to
+//!   `res/generated/day-N-size-S-seed-X.txt`
+//! - [`run_benchmark`] is the entry point for the `bench` command, timing a solution against generated inputs of
+//!   increasing size
+
+use crate::registry::Solution;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{RngExt, SeedableRng};
+use std::fs;
+use std::io;
+use std::time::Instant;
+
+const GENERATED_DIR: &str = "res/generated";
+
+/// Generates an input of the given size using the random number generator
+type Generator = fn(&mut StdRng, usize) -> String;
+
+/// Each day's generator, along with the smallest size the solution can solve, and a size similar to the real input
+const GENERATORS: [(u8, usize, usize, Generator); 8] = [
+    (1, 1, 4000, secret_entrance),
+    (2, 1, 35, gift_shop),
+    (3, 1, 200, lobby),
+    (4, 1, 135, printing_department),
+    (5, 1, 180, cafeteria),
+    (6, 1, 1000, trash_compactor),
+    (7, 1, 141, laboratories),
+    // Part two needs a connection, so at least two junction boxes
+    (8, 2, 1000, playground),
+];
+
+fn find_generator(day: u8) -> Option<&'static (u8, usize, usize, Generator)> {
+    GENERATORS
+        .iter()
+        .find(|(generator_day, _, _, _)| *generator_day == day)
+}
+
+/// The size of the real input for a day, or `None` if there is no generator for the day
+pub fn default_size(day: u8) -> Option<usize> {
+    find_generator(day).map(|&(_, _, size, _)| size)
+}
+
+/// Generate an input for a day from a seed, or `None` if there is no generator for the day
+pub fn generate_input(day: u8, size: usize, seed: u64) -> Option<String> {
+    let &(_, _, _, generator) = find_generator(day)?;
+
+    Some(generator(&mut StdRng::seed_from_u64(seed), size))
+}
+
+/// The entry point for the `generate <day> [size] [seed]` command. The size defaults to that of the real input, and
+/// sizes too small for the day's solution are rejected.
+pub fn write_generated_input(day: u8, size: Option<usize>, seed: u64) -> io::Result<()> {
+    let Some(&(_, min_size, default_size, _)) = find_generator(day) else {
+        println!("There is no generator for day {}", day);
+        return Ok(());
+    };
+    let size = size.unwrap_or(default_size);
+    if size < min_size {
+        println!("The size for day {} must be at least {}", day, min_size);
+        return Ok(());
+    }
+
+    let input = generate_input(day, size, seed).expect("Generators have a default size");
+
+    fs::create_dir_all(GENERATED_DIR)?;
+    let output_filename = format!(
+        "{}/day-{}-size-{}-seed-{}.txt",
+        GENERATED_DIR, day, size, seed
+    );
+    fs::write(&output_filename, input)?;
+    println!("Generated input saved to {}", output_filename);
+
+    Ok(())
+}
+
+/// The entry point for the `bench <day> [seed]` command. Solves generated inputs from a quarter of the size of the real
+/// input up to four times it, doubling each time, and reports how long each took to show how the solution scales.
+pub fn run_benchmark(solution: &Solution, seed: u64) {
+    let Some(real_size) = default_size(solution.day) else {
+        println!("There is no generator for day {}", solution.day);
+        return;
+    };
+
+    println!("{:>8}  {:>12}", "Size", "Time");
+    for size in [
+        real_size / 4,
+        real_size / 2,
+        real_size,
+        real_size * 2,
+        real_size * 4,
+    ] {
+        let input =
+            generate_input(solution.day, size, seed).expect("Generators have a default size");
+
+        let start = Instant::now();
+        (solution.solve)(&input);
+        println!("{:>8}  {:>12.2?}", size, start.elapsed());
+    }
+}
+
+/// Day 1 - `size` instructions, one per line, e.g. `L68`. Some distances are exact multiples of 100.
+fn secret_entrance(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            let distance = if rng.random_bool(0.05) {
+                rng.random_range(1..10) * 100
+            } else {
+                rng.random_range(1..1000)
+            };
+
+            format!("{}{}\n", direction, distance)
+        })
+        .collect()
+}
+
+/// Day 2 - `size` comma-separated ranges, e.g. `11-22`, on a single line, in a random order. The ranges don't
+/// overlap, and can cross powers of ten.
+///
+/// The starts are picked first and sorted, then each range begins at its start or just after the previous range if
+/// that ends later, so the ranges are disjoint however many are asked for.
+fn gift_shop(rng: &mut StdRng, size: usize) -> String {
+    let starts: Vec<u64> = (0..size)
+        .map(|_| {
+            let digits = rng.random_range(1..=10);
+            rng.random_range(10u64.pow(digits - 1)..10u64.pow(digits))
+        })
+        .sorted()
+        .collect();
+
+    let mut next_free = 1;
+    let mut ranges: Vec<(u64, u64)> = starts
+        .into_iter()
+        .map(|start| {
+            let min = start.max(next_free);
+            let max = min + rng.random_range(0..10u64.pow((min.ilog10() + 1).div_ceil(2)));
+            next_free = max + 1;
+
+            (min, max)
+        })
+        .collect();
+    ranges.shuffle(rng);
+
+    ranges
+        .iter()
+        .map(|(min, max)| format!("{}-{}", min, max))
+        .join(",")
+        + "\n"
+}
+
+/// Day 3 - `size` banks of 100 batteries, each a digit from 1 to 9
+fn lobby(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let bank: String = (0..100)
+                .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                .collect();
+            bank + "\n"
+        })
+        .collect()
+}
+
+/// Day 4 - a `size` by `size` grid of the floor, where `@` is a roll of paper
+fn printing_department(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| if rng.random_bool(0.6) { '@' } else { '.' })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+/// Day 5 - `size` fresh ranges, which often overlap, then a blank line, then `size * 5` ids to check
+fn cafeteria(rng: &mut StdRng, size: usize) -> String {
+    let max_id = 500_000_000_000_000u64;
+    let ranges: String = (0..size)
+        .map(|_| {
+            let min = rng.random_range(1..max_id);
+            let max = min + rng.random_range(0..max_id / 50);
+            format!("{}-{}\n", min, max)
+        })
+        .collect();
+    let ids: String = (0..size * 5)
+        .map(|_| format!("{}\n", rng.random_range(1..max_id)))
+        .collect();
+
+    format!("{}\n{}", ranges, ids)
+}
+
+/// Day 6 - `size` problems side by side, each a column of four numbers aligned left or right within the column,
+/// with the operator on the final row
+fn trash_compactor(rng: &mut StdRng, size: usize) -> String {
+    let problems: Vec<(char, Vec<String>)> = (0..size)
+        .map(|_| {
+            let operator = if rng.random_bool(0.5) { '+' } else { '*' };
+            let nums: Vec<String> = (0..4)
+                .map(|_| rng.random_range(1..10_000u32).to_string())
+                .collect();
+            let width = nums.iter().map(String::len).max().unwrap();
+            let left_aligned = rng.random_bool(0.5);
+            let nums = nums
+                .into_iter()
+                .map(|num| {
+                    if left_aligned {
+                        format!("{:<1$}", num, width)
+                    } else {
+                        format!("{:>1$}", num, width)
+                    }
+                })
+                .collect();
+
+            (operator, nums)
+        })
+        .collect();
+
+    let mut lines: Vec<String> = (0..4)
+        .map(|row| problems.iter().map(|(_, nums)| &nums[row]).join(" "))
+        .collect();
+    lines.push(
+        problems
+            .iter()
+            .map(|(operator, nums)| format!("{:<1$}", operator, nums[0].len()))
+            .join(" "),
+    );
+
+    lines.join("\n") + "\n"
+}
+
+/// Day 7 - a manifold `size` wide and `size + 1` high, with the source in the middle of the top row, and splitters on
+/// alternate rows below it. As in the real input, splitters are never on the edges or next to each other, so the split
+/// beams always land on empty cells within the manifold.
+fn laboratories(rng: &mut StdRng, size: usize) -> String {
+    (0..=size)
+        .map(|y| {
+            let mut row = vec!['.'; size];
+            if y == 0 {
+                row[size / 2] = 'S';
+            } else if y % 2 == 0 {
+                for x in 1..size.saturating_sub(1) {
+                    if row[x - 1] != '^' && rng.random_bool(0.3) {
+                        row[x] = '^';
+                    }
+                }
+            }
+
+            row.into_iter().collect::<String>()
+        })
+        .join("\n")
+        + "\n"
+}
+
+/// Day 8 - `size` junction boxes, as `x,y,z` coordinates from 0 to 99,999
+fn playground(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.random_range(0..100_000));
+            format!("{},{},{}\n", x, y, z)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::*;
+    use crate::registry::find_solution;
+    use std::path::Path;
+
+    #[test]
+    fn can_generate_reproducible_inputs() {
+        for &(day, _, _, _) in &GENERATORS {
+            assert_eq!(generate_input(day, 10, 1), generate_input(day, 10, 1));
+            assert_ne!(generate_input(day, 10, 1), generate_input(day, 10, 2));
+        }
+
+        assert_eq!(generate_input(9, 10, 1), None);
+        assert_eq!(default_size(4), Some(135));
+    }
+
+    #[test]
+    fn can_solve_generated_inputs() {
+        for &(day, _, _, _) in &GENERATORS {
+            let solution = find_solution(day).unwrap();
+            for seed in 0..5 {
+                let input = generate_input(day, 20, seed).unwrap();
+                let (part_one, part_two) = (solution.solve)(&input);
+
+                assert!(
+                    !part_one.is_empty() && !part_two.is_empty(),
+                    "Day {} failed to solve the input for seed {}",
+                    day,
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn can_solve_smallest_generated_inputs() {
+        for &(day, min_size, _, _) in &GENERATORS {
+            let solution = find_solution(day).unwrap();
+            let input = generate_input(day, min_size, 0).unwrap();
+            let (part_one, part_two) = (solution.solve)(&input);
+
+            assert!(
+                !part_one.is_empty() && !part_two.is_empty(),
+                "Day {} failed to solve an input of size {}",
+                day,
+                min_size
+            );
+        }
+    }
+
+    #[test]
+    fn cannot_write_input_below_minimum_size() {
+        write_generated_input(7, Some(0), 0).unwrap();
+        write_generated_input(8, Some(1), 0).unwrap();
+
+        assert!(!Path::new("res/generated/day-7-size-0-seed-0.txt").exists());
+        assert!(!Path::new("res/generated/day-8-size-1-seed-0.txt").exists());
+    }
+
+    #[test]
+    fn can_generate_sized_inputs() {
+        assert_eq!(generate_input(1, 25, 0).unwrap().lines().count(), 25);
+        assert_eq!(generate_input(2, 7, 0).unwrap().split(',').count(), 7);
+        assert_eq!(generate_input(3, 4, 0).unwrap().lines().count(), 4);
+
+        let grid = generate_input(4, 6, 0).unwrap();
+        assert_eq!(grid.lines().count(), 6);
+        assert!(grid.lines().all(|line| line.len() == 6));
+
+        let (ranges, ids) = generate_input(5, 3, 0)
+            .unwrap()
+            .split_once("\n\n")
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .unwrap();
+        assert_eq!(ranges.lines().count(), 3);
+        assert_eq!(ids.lines().count(), 15);
+
+        let worksheet = generate_input(6, 5, 0).unwrap();
+        assert_eq!(
+            worksheet.lines().last().unwrap().split_whitespace().count(),
+            5
+        );
+
+        let manifold = generate_input(7, 9, 0).unwrap();
+        assert_eq!(manifold.lines().next(), Some("....S...."));
+        assert_eq!(manifold.lines().count(), 10);
+
+        assert_eq!(generate_input(8, 12, 0).unwrap().lines().count(), 12);
+    }
+
+    #[test]
+    fn can_generate_disjoint_gift_shop_ranges() {
+        let input = generate_input(2, 20_000, 0).unwrap();
+        let ranges: Vec<(u64, u64)> = input
+            .trim()
+            .split(',')
+            .map(|range| {
+                let (min, max) = range.split_once('-').unwrap();
+                (min.parse().unwrap(), max.parse().unwrap())
+            })
+            .sorted()
+            .collect();
+
+        assert_eq!(ranges.len(), 20_000);
+        assert!(ranges.iter().all(|&(min, max)| min <= max));
+        assert!(ranges.windows(2).all(|pair| pair[0].1 < pair[1].0));
+    }
+
+    #[test]
+    fn can_generate_manifolds_like_the_real_input() {
+        let manifold = generate_input(7, 40, 0).unwrap();
+
+        assert!(manifold.lines().any(|row| row.contains('^')));
+        assert!(
+            manifold
+                .lines()
+                .all(|row| { !row.starts_with('^') && !row.ends_with('^') && !row.contains("^^") })
+        );
+    }
+}
@@ -0,0 +1,400 @@
+//! Points and directions in 2D and 3D space, so that each day can share a vocabulary for positions.
+//!
+//! - [`Point2`] and [`Point3`] support component-wise arithmetic, and scaling by a single value
+//! - [`Point2::manhattan_distance`], [`Point2::chebyshev_distance`] and [`Point2::squared_euclidean_distance`] (and
+//!   their 3D equivalents) measure how far apart two points are
+//! - [`Direction`] and [`Direction8`] name the four orthogonal and eight surrounding directions on a grid, with the
+//!   origin in the top-left, so `Up` decreases `y`
+//! - [`Point2::checked_step`] moves an unsigned point, returning `None` rather than underflowing
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The arithmetic needed by the distance calculations. Implemented for all the primitive numeric types.
+pub trait Number:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// The absolute difference, without the intermediate underflowing for unsigned types
+    fn difference(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(impl Number for $t {})*
+    };
+}
+
+impl_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+/// The larger of two values, for types that are only `PartialOrd`
+#[allow(dead_code)]
+fn max<T: Number>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+/// A point in 2D space
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Default, Ord, PartialOrd)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point in 3D space
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Default, Ord, PartialOrd)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Number> Point2<T> {
+    /// The distance when only moving orthogonally
+    pub fn manhattan_distance(&self, other: &Point2<T>) -> T {
+        self.x.difference(other.x) + self.y.difference(other.y)
+    }
+
+    /// The distance when diagonal moves are allowed, and cost the same as orthogonal moves
+    pub fn chebyshev_distance(&self, other: &Point2<T>) -> T {
+        max(self.x.difference(other.x), self.y.difference(other.y))
+    }
+
+    /// The straight line distance squared, which avoids needing a square root to compare distances
+    pub fn squared_euclidean_distance(&self, other: &Point2<T>) -> T {
+        let (dx, dy) = (self.x.difference(other.x), self.y.difference(other.y));
+        dx * dx + dy * dy
+    }
+}
+
+impl<T: Number> Point3<T> {
+    /// The distance when only moving parallel to an axis
+    #[allow(dead_code)]
+    pub fn manhattan_distance(&self, other: &Point3<T>) -> T {
+        self.x.difference(other.x) + self.y.difference(other.y) + self.z.difference(other.z)
+    }
+
+    /// The distance when diagonal moves are allowed, and cost the same as orthogonal moves
+    #[allow(dead_code)]
+    pub fn chebyshev_distance(&self, other: &Point3<T>) -> T {
+        max(
+            max(self.x.difference(other.x), self.y.difference(other.y)),
+            self.z.difference(other.z),
+        )
+    }
+
+    /// The straight line distance squared, which avoids needing a square root to compare distances
+    pub fn squared_euclidean_distance(&self, other: &Point3<T>) -> T {
+        let (dx, dy, dz) = (
+            self.x.difference(other.x),
+            self.y.difference(other.y),
+            self.z.difference(other.z),
+        );
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+impl Point2<usize> {
+    /// Offset the point, returning `None` if either coordinate would be negative
+    pub fn checked_offset(&self, offset: Point2<isize>) -> Option<Point2<usize>> {
+        Some(Point2::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+
+    /// Move one step in a direction, returning `None` if that would leave the positive quadrant
+    pub fn checked_step(&self, direction: impl Into<Point2<isize>>) -> Option<Point2<usize>> {
+        self.checked_offset(direction.into())
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Self::Output {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Self::Output {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// The four orthogonal directions on a grid
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Direction {
+    #[allow(
+        dead_code,
+        reason = "no puzzle has stepped up yet, but the set of directions should be complete"
+    )]
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise from `Up`
+    #[allow(dead_code)]
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    #[allow(dead_code)]
+    pub fn turn_clockwise(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_anticlockwise(&self) -> Direction {
+        self.reverse().turn_clockwise()
+    }
+
+    #[allow(dead_code)]
+    pub fn reverse(&self) -> Direction {
+        self.turn_clockwise().turn_clockwise()
+    }
+
+    /// The change in position from moving one step in this direction
+    pub fn offset(&self) -> Point2<isize> {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+impl From<Direction> for Point2<isize> {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+/// The eight directions to the cells surrounding a cell on a grid
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The change in position from moving one step in this direction
+    pub fn offset(&self) -> Point2<isize> {
+        match self {
+            Direction8::Up => Point2::new(0, -1),
+            Direction8::UpRight => Point2::new(1, -1),
+            Direction8::Right => Point2::new(1, 0),
+            Direction8::DownRight => Point2::new(1, 1),
+            Direction8::Down => Point2::new(0, 1),
+            Direction8::DownLeft => Point2::new(-1, 1),
+            Direction8::Left => Point2::new(-1, 0),
+            Direction8::UpLeft => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction8> for Point2<isize> {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::geometry::*;
+
+    #[test]
+    fn can_do_point_arithmetic() {
+        assert_eq!(Point2::new(1, 2) + Point2::new(3, -4), Point2::new(4, -2));
+        assert_eq!(Point2::new(1, 2) - Point2::new(3, -4), Point2::new(-2, 6));
+        assert_eq!(Point2::new(1, -2) * 3, Point2::new(3, -6));
+        assert_eq!(-Point2::new(1, -2), Point2::new(-1, 2));
+
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(4, 5, 6),
+            Point3::new(5, 7, 9)
+        );
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::new(4, 5, 6),
+            Point3::new(-3, -3, -3)
+        );
+        assert_eq!(Point3::new(1, 2, 3) * 2, Point3::new(2, 4, 6));
+        assert_eq!(-Point3::new(1, -2, 3), Point3::new(-1, 2, -3));
+
+        assert_eq!(Point3::from((1, 2, 3)), Point3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn can_measure_distances() {
+        let a = Point2::new(1usize, 5);
+        let b = Point2::new(4usize, 1);
+
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(b.manhattan_distance(&a), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_euclidean_distance(&b), 25);
+
+        let c = Point3::new(162i64, 817, 812);
+        let d = Point3::new(425i64, 690, 689);
+
+        assert_eq!(c.manhattan_distance(&d), 263 + 127 + 123);
+        assert_eq!(c.chebyshev_distance(&d), 263);
+        assert_eq!(
+            c.squared_euclidean_distance(&d),
+            263 * 263 + 127 * 127 + 123 * 123
+        );
+    }
+
+    #[test]
+    fn can_step_in_directions() {
+        let origin = Point2::new(0usize, 0);
+        let point = Point2::new(3usize, 2);
+
+        assert_eq!(origin.checked_step(Direction::Up), None);
+        assert_eq!(origin.checked_step(Direction::Left), None);
+        assert_eq!(
+            origin.checked_step(Direction8::DownRight),
+            Some(Point2::new(1, 1))
+        );
+        assert_eq!(point.checked_step(Direction::Up), Some(Point2::new(3, 1)));
+        assert_eq!(
+            point.checked_offset(Point2::new(-3, 2)),
+            Some(Point2::new(0, 4))
+        );
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.offset(), Direction8::from(direction).offset());
+        }
+    }
+
+    #[test]
+    fn can_turn() {
+        assert_eq!(Direction::Up.turn_clockwise(), Direction::Right);
+        assert_eq!(Direction::Left.turn_clockwise(), Direction::Up);
+        assert_eq!(Direction::Up.turn_anticlockwise(), Direction::Left);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Direction8::from(Direction::Down), Direction8::Down);
+    }
+}
@@ -0,0 +1,377 @@
+//! Graph searches, for puzzles that need a shortest route or an ordering of dependencies.
+//!
+//! The graph is never built up front. Each search is given a function that lists the neighbours of a node, so the same
+//! searches work over a [`Grid`](crate::helpers::grid::Grid), or over an implicit space of puzzle states.
+//!
+//! - [`bfs`] finds the path with the fewest steps when every step costs the same
+//! - [`bfs_distances`] finds the number of steps to every reachable node
+//! - [`dijkstra`] finds the cheapest path when each step has its own cost
+//! - [`astar`] does the same, using a heuristic to explore towards the goal first
+//! - [`topological_sort`] orders nodes so that each comes before its successors
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The arithmetic needed for a cost. [`Default`] is used for the zero cost of the start node.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// The result of a successful search
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SearchResult<N, C> {
+    /// The total cost of the path. For [`bfs`] this is the number of steps.
+    pub cost: C,
+    /// The nodes visited, including the start and goal
+    pub path: Vec<N>,
+}
+
+/// Follow the parent links back from `node` to the start, returning the path from the start
+fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, node: &N) -> Vec<N> {
+    let mut path = vec![node.clone()];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+/// Breadth-first search from `start` to the first node matching `is_goal`, where every step costs 1. Returns `None` if
+/// no goal is reachable.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<SearchResult<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut visited: HashSet<N> = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let path = reconstruct_path(&parents, &node);
+            return Some(SearchResult {
+                cost: path.len() - 1,
+                path,
+            });
+        }
+
+        for next in neighbours(&node) {
+            if visited.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Breadth-first search from `start` to every reachable node, returning the number of steps to each
+pub fn bfs_distances<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Find the cheapest path from `start` to the first node matching `is_goal`. `neighbours` returns each neighbour with
+/// the cost of stepping to it, which must not be negative. Returns `None` if no goal is reachable.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<SearchResult<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Find the cheapest path from `start` to the first node matching `is_goal`, exploring the nodes with the lowest cost
+/// plus `heuristic` first. The heuristic estimates the remaining cost to a goal, and must never overestimate it, or a
+/// more expensive path may be returned. Returns `None` if no goal is reachable.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<SearchResult<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Nodes are numbered as they are found, so that the queue doesn't need the nodes to be ordered
+    let mut nodes: Vec<N> = vec![start.clone()];
+    let mut indices: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut costs: Vec<C> = vec![C::default()];
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        let cost = costs[index];
+        if is_goal(&node) {
+            return Some(SearchResult {
+                cost,
+                path: reconstruct_path(&parents, &node),
+            });
+        }
+
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.get(&next) {
+                Some(&existing) if costs[existing] <= next_cost => continue,
+                Some(&existing) => {
+                    costs[existing] = next_cost;
+                    existing
+                }
+                None => {
+                    nodes.push(next.clone());
+                    costs.push(next_cost);
+                    indices.insert(next.clone(), nodes.len() - 1);
+                    nodes.len() - 1
+                }
+            };
+
+            parents.insert(next.clone(), node.clone());
+            queue.push(Reverse((next_cost + heuristic(&next), next_index)));
+        }
+    }
+
+    None
+}
+
+/// Order `nodes` so that each node comes before all of its `successors`, using Kahn's algorithm. Nodes with no
+/// predecessors start the order in the order they were given. Successors that are not in `nodes` are ignored. Returns a
+/// node on a cycle if there is one, as no such order exists.
+pub fn topological_sort<N, I>(nodes: &[N], mut successors: impl FnMut(&N) -> I) -> Result<Vec<N>, N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let edges: HashMap<&N, Vec<N>> = nodes
+        .iter()
+        .map(|node| (node, successors(node).into_iter().collect()))
+        .collect();
+
+    let mut in_degrees: HashMap<&N, usize> = nodes.iter().map(|node| (node, 0)).collect();
+    for successor in edges.values().flatten() {
+        if let Some(in_degree) = in_degrees.get_mut(successor) {
+            *in_degree += 1;
+        }
+    }
+
+    let mut ready: VecDeque<&N> = nodes.iter().filter(|node| in_degrees[node] == 0).collect();
+    let mut sorted = Vec::with_capacity(nodes.len());
+
+    while let Some(node) = ready.pop_front() {
+        sorted.push(node.clone());
+        for successor in &edges[node] {
+            if let Some((&successor, in_degree)) = in_degrees.get_key_value(successor) {
+                let in_degree = *in_degree - 1;
+                in_degrees.insert(successor, in_degree);
+                if in_degree == 0 {
+                    ready.push_back(successor);
+                }
+            }
+        }
+    }
+
+    match nodes.iter().find(|node| in_degrees[node] > 0) {
+        Some(on_cycle) => Err(on_cycle.clone()),
+        None => Ok(sorted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::geometry::Point2;
+    use crate::helpers::graph::*;
+    use crate::helpers::grid::Grid;
+
+    fn sample_maze() -> Grid<bool> {
+        Grid::parse(
+            "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E",
+            |c| c != '#',
+        )
+    }
+
+    fn open_neighbours(maze: &Grid<bool>, position: &Point2<usize>) -> Vec<Point2<usize>> {
+        maze.neighbours_4(*position)
+            .filter(|&next| maze[next])
+            .collect()
+    }
+
+    #[test]
+    fn can_search_breadth_first() {
+        let maze = sample_maze();
+        let goal = Point2::new(7, 4);
+
+        let result = bfs(
+            Point2::new(0, 0),
+            |position| open_neighbours(&maze, position),
+            |&position| position == goal,
+        )
+        .unwrap();
+
+        assert_eq!(result.cost, 15);
+        assert_eq!(result.path.len(), 16);
+        assert_eq!(result.path.first(), Some(&Point2::new(0, 0)));
+        assert_eq!(result.path.last(), Some(&goal));
+        assert!(
+            result
+                .path
+                .windows(2)
+                .all(|step| step[0].manhattan_distance(&step[1]) == 1 && maze[step[1]])
+        );
+
+        assert_eq!(
+            bfs(
+                Point2::new(0, 0),
+                |position| open_neighbours(&maze, position),
+                |&position| position == Point2::new(3, 0),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn can_find_all_distances() {
+        let distances = bfs_distances(0u32, |&n| [n + 1, n * 2].into_iter().filter(|&n| n <= 10));
+
+        assert_eq!(distances.len(), 11);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&1], 1);
+        assert_eq!(distances[&8], 4);
+        assert_eq!(distances[&10], 5);
+    }
+
+    /// A small weighted graph, where the direct routes are more expensive than the indirect ones
+    fn weighted_edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('a', 7), ('c', 10), ('d', 15)],
+            'c' => vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)],
+            'd' => vec![('b', 15), ('c', 11), ('e', 6)],
+            'e' => vec![('d', 6), ('f', 9)],
+            'f' => vec![('a', 14), ('c', 2), ('e', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn can_find_cheapest_path() {
+        assert_eq!(
+            dijkstra('a', weighted_edges, |&node| node == 'e'),
+            Some(SearchResult {
+                cost: 20,
+                path: vec!['a', 'c', 'f', 'e'],
+            })
+        );
+        assert_eq!(
+            dijkstra('a', weighted_edges, |&node| node == 'a'),
+            Some(SearchResult {
+                cost: 0,
+                path: vec!['a'],
+            })
+        );
+        assert_eq!(dijkstra('a', weighted_edges, |&node| node == 'z'), None);
+    }
+
+    #[test]
+    fn can_find_cheapest_path_with_heuristic() {
+        let maze = sample_maze();
+        let goal = Point2::new(7, 4);
+
+        let weighted_neighbours = |position: &Point2<usize>| {
+            open_neighbours(&maze, position)
+                .into_iter()
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let with_heuristic = astar(
+            Point2::new(0, 0),
+            weighted_neighbours,
+            |position| position.manhattan_distance(&goal),
+            |&position| position == goal,
+        )
+        .unwrap();
+        let without_heuristic = dijkstra(Point2::new(0, 0), weighted_neighbours, |&position| {
+            position == goal
+        })
+        .unwrap();
+
+        assert_eq!(with_heuristic.cost, 15);
+        assert_eq!(with_heuristic.cost, without_heuristic.cost);
+        assert_eq!(with_heuristic.path.last(), Some(&goal));
+    }
+
+    #[test]
+    fn can_sort_topologically() {
+        let dependencies = |node: &char| match node {
+            'a' => vec!['b', 'c'],
+            'b' => vec!['d'],
+            'c' => vec!['d'],
+            'd' => vec!['e'],
+            _ => vec![],
+        };
+
+        let sorted = topological_sort(&['e', 'd', 'c', 'b', 'a'], dependencies).unwrap();
+        let position = |node: char| sorted.iter().position(|&n| n == node).unwrap();
+
+        assert_eq!(sorted.len(), 5);
+        assert_eq!(sorted.first(), Some(&'a'));
+        assert!(position('b') < position('d'));
+        assert!(position('c') < position('d'));
+        assert_eq!(sorted.last(), Some(&'e'));
+
+        assert_eq!(
+            topological_sort(&['x', 'y', 'a'], dependencies),
+            Ok(vec!['x', 'y', 'a'])
+        );
+
+        let cyclic = |node: &char| match node {
+            'a' => vec!['b'],
+            'b' => vec!['c'],
+            'c' => vec!['b'],
+            _ => vec![],
+        };
+
+        assert!(matches!(
+            topological_sort(&['a', 'b', 'c'], cyclic),
+            Err('b') | Err('c')
+        ));
+    }
+}
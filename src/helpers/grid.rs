@@ -0,0 +1,354 @@
+//! A dense 2D grid, for the many puzzles whose input is a rectangle of characters.
+//!
+//! - [`Grid::parse`] builds a grid from puzzle input, mapping each character to a cell
+//! - [`Grid::get`] and indexing with a [`Point2`] provide access, with the origin in the top-left
+//! - [`Grid::neighbours_4`] and [`Grid::neighbours_8`] iterate the in-bounds positions adjacent to a cell, clockwise
+//!   from the cell above
+//! - [`Grid::rows`] and [`Grid::column`] iterate along each axis
+//! - [`Grid::transpose`], [`Grid::rotate_clockwise`] and [`Grid::rotate_anticlockwise`] reorient the grid
+//! - [`Display`] renders the grid back to text, one row per line
+
+use crate::helpers::geometry::{Direction, Direction8, Point2};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells stored row by row
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from a list of cells in reading order. Panics if there are not `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {width}x{height} grid needs {} cells",
+            width * height
+        );
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Build a grid by calling `cell` with each position
+    pub fn from_fn(width: usize, height: usize, cell: impl Fn(Point2<usize>) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2::new(x, y)))
+            .map(cell)
+            .collect();
+
+        Grid::new(width, height, cells)
+    }
+
+    /// Parse the lines of the input as the rows of the grid, mapping each character to a cell. Panics if the lines
+    /// are not all the same length.
+    pub fn parse(input: &str, cell: impl Fn(char) -> T) -> Grid<T> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let row_start = cells.len();
+            cells.extend(line.chars().map(&cell));
+
+            let row_width = cells.len() - row_start;
+            if height == 0 {
+                width = row_width
+            }
+            assert_eq!(
+                row_width, width,
+                "Row {height} of the grid is {row_width} cells wide, but the rows above it are {width} cells wide"
+            );
+            height += 1;
+        }
+
+        Grid::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point2<usize>) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Point2<usize>) -> Option<&T> {
+        if self.contains(position) {
+            self.cells.get(position.y * self.width + position.x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Point2<usize>) -> Option<&mut T> {
+        if self.contains(position) {
+            self.cells.get_mut(position.y * self.width + position.x)
+        } else {
+            None
+        }
+    }
+
+    /// Step from a position in the given directions, keeping those that land within the grid
+    fn step_all<D: Into<Point2<isize>>>(
+        &self,
+        position: Point2<usize>,
+        directions: impl IntoIterator<Item = D>,
+    ) -> impl Iterator<Item = Point2<usize>> {
+        directions
+            .into_iter()
+            .filter_map(move |direction| position.checked_step(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// The positions orthogonally adjacent to `position` that are within the grid
+    #[allow(dead_code)]
+    pub fn neighbours_4(
+        &self,
+        position: Point2<usize>,
+    ) -> impl Iterator<Item = Point2<usize>> + '_ {
+        self.step_all(position, Direction::ALL)
+    }
+
+    /// The positions orthogonally or diagonally adjacent to `position` that are within the grid
+    pub fn neighbours_8(
+        &self,
+        position: Point2<usize>,
+    ) -> impl Iterator<Item = Point2<usize>> + '_ {
+        self.step_all(position, Direction8::ALL)
+    }
+
+    /// Each cell with its position, in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (Point2::new(index % self.width, index / self.width), cell))
+    }
+
+    /// The positions of cells matching a predicate, in reading order
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2<usize>> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    #[allow(dead_code)]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    #[allow(dead_code)]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |y| &self[Point2::new(x, y)])
+    }
+
+    #[allow(dead_code)]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Build a grid of the same dimensions with each cell mapped
+    #[allow(dead_code)]
+    pub fn map<U>(&self, cell: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(cell).collect(),
+        )
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Clone> Grid<T> {
+    /// Build a grid with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Swap rows and columns, i.e. reflect in the leading diagonal
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |position| {
+            self[Point2::new(position.y, position.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn, so the first column becomes the first row read right to left
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |position| {
+            self[Point2::new(position.y, self.height - 1 - position.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn, so the last column becomes the first row
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |position| {
+            self[Point2::new(self.width - 1 - position.y, position.x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    /// Panics if the position is outside the grid
+    fn index(&self, position: Point2<usize>) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    /// Panics if the position is outside the grid
+    fn index_mut(&mut self, position: Point2<usize>) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    /// Renders each row on its own line, with cells written without separators
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::grid::*;
+
+    fn points(positions: &[(usize, usize)]) -> Vec<Point2<usize>> {
+        positions
+            .iter()
+            .map(|&position| Point2::from(position))
+            .collect()
+    }
+
+    fn sample_grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn can_parse_grid() {
+        let grid = sample_grid();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point2::new(0, 0)], 'a');
+        assert_eq!(grid[Point2::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, 2)), None);
+
+        assert_eq!(
+            Grid::parse("#.\n.#", |c| c == '#'),
+            Grid::new(2, 2, vec![true, false, false, true])
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Row 1 of the grid is 2 cells wide, but the rows above it are 3 cells wide"
+    )]
+    fn cannot_parse_ragged_grid() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn can_update_cells() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[Point2::new(1, 0)] = 5;
+        *grid.get_mut(Point2::new(0, 1)).unwrap() += 2;
+
+        assert_eq!(grid, Grid::new(2, 2, vec![0, 5, 2, 0]));
+        assert_eq!(grid.get_mut(Point2::new(2, 2)), None);
+    }
+
+    #[test]
+    fn can_find_neighbours() {
+        let grid = Grid::filled(3, 3, ());
+
+        assert_eq!(
+            grid.neighbours_4(Point2::new(1, 1)).collect::<Vec<_>>(),
+            points(&[(1, 0), (2, 1), (1, 2), (0, 1)])
+        );
+        assert_eq!(
+            grid.neighbours_4(Point2::new(0, 0)).collect::<Vec<_>>(),
+            points(&[(1, 0), (0, 1)])
+        );
+        assert_eq!(grid.neighbours_8(Point2::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours_8(Point2::new(2, 2)).collect::<Vec<_>>(),
+            points(&[(2, 1), (1, 2), (1, 1)])
+        );
+    }
+
+    #[test]
+    fn can_iterate_rows_and_columns() {
+        let grid = sample_grid();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.positions(|&c| c == 'b' || c == 'f')
+                .collect::<Vec<_>>(),
+            points(&[(1, 0), (2, 1)])
+        );
+    }
+
+    #[test]
+    fn can_reorient_grid() {
+        let grid = sample_grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+    }
+
+    #[test]
+    fn can_render_grid() {
+        let grid = Grid::parse("#.\n.#", |c| c == '#');
+
+        assert_eq!(
+            grid.map(|&roll| if roll { '@' } else { '.' }).to_string(),
+            "@.\n.@"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Memoisation for recursive functions over hashable state, built on a `cached` [`UnboundCache`].
+//!
+//! The `#[cached]` macro needs a free function whose arguments are all part of the key, which doesn't suit a function
+//! that captures the puzzle data it is searching. [`Memoised`] instead wraps a closure, which is passed a `recurse`
+//! function to call in place of itself, so each recursive call is looked up in the cache first.
+//!
+//! ```ignore
+//! let fibonacci = Memoised::new(|recurse: &dyn Fn(u64) -> u64, n: u64| {
+//!     if n < 2 { n } else { recurse(n - 1) + recurse(n - 2) }
+//! });
+//!
+//! assert_eq!(fibonacci.get(90), 2880067194370816120);
+//! println!("{}", fibonacci.stats());
+//! ```
+
+use cached::{Cached, UnboundCache};
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// How well the cache has performed
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// The number of values stored
+    pub size: usize,
+}
+
+impl CacheStats {
+    /// The proportion of lookups that were found in the cache, from `0.0` to `1.0`
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} cached values",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.size
+        )
+    }
+}
+
+/// A recursive function with its results cached by argument
+pub struct Memoised<K, V, F> {
+    function: F,
+    cache: RefCell<UnboundCache<K, V>>,
+}
+
+impl<K, V, F> Memoised<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&dyn Fn(K) -> V, K) -> V,
+{
+    /// Wrap `function`, which is called with a function to use for recursive calls, and the argument
+    pub fn new(function: F) -> Memoised<K, V, F> {
+        Memoised {
+            function,
+            cache: RefCell::new(UnboundCache::new()),
+        }
+    }
+
+    /// The result of the function for `key`, which is only computed if it isn't already cached
+    pub fn get(&self, key: K) -> V {
+        let cached = self.cache.borrow_mut().cache_get(&key).cloned();
+        if let Some(value) = cached {
+            return value;
+        }
+
+        let value = (self.function)(&|key| self.get(key), key.clone());
+        self.cache.borrow_mut().cache_set(key, value.clone());
+
+        value
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let cache = self.cache.borrow();
+
+        CacheStats {
+            hits: cache.cache_hits().unwrap_or_default(),
+            misses: cache.cache_misses().unwrap_or_default(),
+            size: cache.cache_size(),
+        }
+    }
+
+    /// Empty the cache, and reset the statistics
+    #[allow(dead_code)]
+    pub fn clear(&self) {
+        let mut cache = self.cache.borrow_mut();
+        cache.cache_clear();
+        cache.cache_reset_metrics();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::memo::*;
+    use crate::helpers::test::assert_approx_eq;
+
+    #[test]
+    fn can_memoise_recursive_function() {
+        let fibonacci = Memoised::new(|recurse: &dyn Fn(u64) -> u64, n: u64| {
+            if n < 2 {
+                n
+            } else {
+                recurse(n - 1) + recurse(n - 2)
+            }
+        });
+
+        assert_eq!(fibonacci.get(10), 55);
+        assert_eq!(
+            fibonacci.stats(),
+            CacheStats {
+                hits: 8,
+                misses: 11,
+                size: 11
+            }
+        );
+
+        assert_eq!(fibonacci.get(90), 2880067194370816120);
+        assert_eq!(fibonacci.stats().size, 91);
+
+        fibonacci.clear();
+        assert_eq!(
+            fibonacci.stats(),
+            CacheStats {
+                hits: 0,
+                misses: 0,
+                size: 0
+            }
+        );
+    }
+
+    #[test]
+    fn can_capture_puzzle_data() {
+        let coins = [1, 2, 5];
+        let ways_to_make =
+            Memoised::new(|recurse: &dyn Fn((usize, usize)) -> u64, (total, coin)| {
+                if total == 0 {
+                    1
+                } else if coin == coins.len() {
+                    0
+                } else {
+                    (0..=total / coins[coin])
+                        .map(|count| recurse((total - count * coins[coin], coin + 1)))
+                        .sum()
+                }
+            });
+
+        assert_eq!(ways_to_make.get((5, 0)), 4);
+        assert_eq!(ways_to_make.get((100, 0)), 541);
+    }
+
+    #[test]
+    fn can_report_stats() {
+        let stats = CacheStats {
+            hits: 3,
+            misses: 1,
+            size: 1,
+        };
+
+        assert_approx_eq(stats.hit_rate(), 0.75, 1e-9);
+        assert_eq!(
+            stats.to_string(),
+            "3 hits, 1 misses (75.0% hit rate), 1 cached values"
+        );
+    }
+}
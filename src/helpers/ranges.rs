@@ -0,0 +1,301 @@
+//! A set of integers stored as inclusive ranges, for puzzles whose input is a list of ranges that may overlap.
+//!
+//! - [`IntervalSet::insert`] adds a range, merging it with any ranges it overlaps or touches
+//! - [`IntervalSet::union`], [`IntervalSet::intersection`] and [`IntervalSet::difference`] combine sets
+//! - [`IntervalSet::contains`] checks membership with a binary search
+//! - [`IntervalSet::covered_count`] counts how many integers are in the set, as long as the count fits in a `u128`
+//! - [`IntervalSet::iter`] iterates the merged ranges in ascending order, as `(min, max)` pairs
+
+use std::fmt::Debug;
+
+/// The operations on the bounds of a range needed by [`IntervalSet`]. Implemented for all the primitive integer types.
+pub trait Integer: Copy + Ord + Debug {
+    /// The next integer, or `None` if this is the maximum value
+    fn successor(self) -> Option<Self>;
+
+    /// The previous integer, or `None` if this is the minimum value
+    fn predecessor(self) -> Option<Self>;
+
+    /// The number of integers in `min..=max`, or `None` for the full range of a 128-bit type, which has one more
+    /// integer than a `u128` can count
+    fn count_inclusive(min: Self, max: Self) -> Option<u128>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count_inclusive(min: Self, max: Self) -> Option<u128> {
+                (max.abs_diff(min) as u128).checked_add(1)
+            }
+        })*
+    };
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// A set of integers, stored as a sorted list of inclusive ranges that neither overlap nor touch
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    /// Add the integers in `min..=max` to the set. Ranges that overlap or touch the new range are merged into it.
+    /// Empty ranges, where `min > max`, are ignored.
+    pub fn insert(&mut self, min: T, max: T) {
+        if min > max {
+            return;
+        }
+
+        // The intervals in `start..end` overlap or touch the new range, so are replaced by the merged range
+        let start = self
+            .intervals
+            .partition_point(|&(_, end)| end.successor().is_some_and(|next| next < min));
+        let end = self
+            .intervals
+            .partition_point(|&(begin, _)| max.successor().is_none_or(|next| begin <= next));
+
+        let merged = if start < end {
+            (
+                min.min(self.intervals[start].0),
+                max.max(self.intervals[end - 1].1),
+            )
+        } else {
+            (min, max)
+        };
+
+        self.intervals.splice(start..end, [merged]);
+    }
+
+    /// Whether `value` is in one of the ranges, found by binary search
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|&(_, max)| max < value);
+
+        self.intervals
+            .get(index)
+            .is_some_and(|&(min, _)| min <= value)
+    }
+
+    /// The integers in either set
+    #[allow(dead_code)]
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for &(min, max) in &other.intervals {
+            union.insert(min, max);
+        }
+
+        union
+    }
+
+    /// The integers in both sets
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intervals
+            .iter()
+            .flat_map(|&(min, max)| {
+                other
+                    .overlapping(min, max)
+                    .map(move |&(other_min, other_max)| (min.max(other_min), max.min(other_max)))
+            })
+            .collect()
+    }
+
+    /// The integers in this set, but not `other`
+    #[allow(dead_code)]
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = IntervalSet::new();
+
+        for &(min, max) in &self.intervals {
+            // The start of the part of this range that hasn't been checked, or `None` once the whole range has
+            let mut remaining = Some(min);
+
+            for &(other_min, other_max) in other.overlapping(min, max) {
+                let Some(start) = remaining else { break };
+                if start < other_min {
+                    // `other_min` must have a predecessor, as it is greater than `start`
+                    difference.insert(start, other_min.predecessor().unwrap());
+                }
+                remaining = other_max.successor();
+            }
+
+            if let Some(start) = remaining
+                && start <= max
+            {
+                difference.insert(start, max);
+            }
+        }
+
+        difference
+    }
+
+    /// The ranges in this set that overlap `min..=max`, in ascending order
+    fn overlapping(&self, min: T, max: T) -> impl Iterator<Item = &(T, T)> {
+        let start = self.intervals.partition_point(|&(_, end)| end < min);
+
+        self.intervals[start..]
+            .iter()
+            .take_while(move |&&(begin, _)| begin <= max)
+    }
+
+    /// The number of integers in the set, or `None` if it covers every value of a 128-bit type so the count doesn't fit
+    /// in a `u128`
+    pub fn covered_count(&self) -> Option<u128> {
+        self.intervals.iter().try_fold(0u128, |count, &(min, max)| {
+            count.checked_add(T::count_inclusive(min, max)?)
+        })
+    }
+
+    /// The number of separate ranges, after merging
+    #[allow(dead_code)]
+    pub fn interval_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The merged ranges as `(min, max)` pairs, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        self.intervals.iter().copied()
+    }
+}
+
+impl<T: Integer> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for (min, max) in ranges {
+            set.insert(min, max);
+        }
+
+        set
+    }
+}
+
+impl<T: Integer> From<(T, T)> for IntervalSet<T> {
+    fn from((min, max): (T, T)) -> Self {
+        IntervalSet::from_iter([(min, max)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::ranges::*;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn can_merge_ranges_on_insert() {
+        let mut ranges = set(&[(10, 14), (3, 5)]);
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![(3, 5), (10, 14)]);
+
+        ranges.insert(16, 20);
+        ranges.insert(12, 18);
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![(3, 5), (10, 20)]);
+
+        // Adjacent ranges are merged too
+        ranges.insert(6, 9);
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![(3, 20)]);
+
+        ranges.insert(5, 4);
+        assert_eq!(ranges.interval_count(), 1);
+
+        ranges.insert(-5, -1);
+        ranges.insert(1, 1);
+        assert_eq!(
+            ranges.iter().collect::<Vec<_>>(),
+            vec![(-5, -1), (1, 1), (3, 20)]
+        );
+    }
+
+    #[test]
+    fn can_merge_ranges_at_the_limits() {
+        let ranges: IntervalSet<u8> = [(250, 255), (0, 3), (4, 249)].into_iter().collect();
+
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![(0, 255)]);
+        assert_eq!(ranges.covered_count(), Some(256));
+    }
+
+    #[test]
+    fn can_check_membership() {
+        let ranges = set(&[(3, 5), (10, 14)]);
+
+        assert!(!ranges.contains(2));
+        assert!(ranges.contains(3));
+        assert!(ranges.contains(5));
+        assert!(!ranges.contains(8));
+        assert!(ranges.contains(14));
+        assert!(!ranges.contains(15));
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn can_combine_sets() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (15, 16), (30, 31)]);
+
+        assert_eq!(a.union(&b), set(&[(1, 20), (30, 31)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 12), (15, 16)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (13, 14), (17, 20)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (30, 31)]));
+
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+
+        let everything: IntervalSet<u8> = IntervalSet::from((0, 255));
+        assert_eq!(
+            everything.difference(&IntervalSet::from((10, 255))),
+            IntervalSet::from((0, 9))
+        );
+    }
+
+    #[test]
+    fn can_count_covered_integers() {
+        assert_eq!(
+            set(&[(3, 5), (10, 14), (12, 18), (16, 20)]).covered_count(),
+            Some(14)
+        );
+        assert_eq!(set(&[(-2, 2)]).covered_count(), Some(5));
+        assert_eq!(IntervalSet::<u64>::new().covered_count(), Some(0));
+        assert_eq!(
+            IntervalSet::from((0, u64::MAX)).covered_count(),
+            Some(u64::MAX as u128 + 1)
+        );
+
+        // Counting every u128 needs one more than u128::MAX, but every one but the first still fits
+        assert_eq!(IntervalSet::from((0, u128::MAX)).covered_count(), None);
+        assert_eq!(
+            IntervalSet::from((1, u128::MAX)).covered_count(),
+            Some(u128::MAX)
+        );
+        assert_eq!(
+            IntervalSet::from_iter([(i128::MIN, -1), (0, i128::MAX)]).covered_count(),
+            None
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Assertions for the day tests, which explain what differs when they fail.
+//!
+//! - [`assert_contains_in_any_order`] compares collections as multisets, listing the missing and unexpected elements
+//! - [`assert_grid_eq`] compares grids, rendering both and listing the cells that differ
+//! - [`assert_approx_eq`] compares floats within a tolerance
+
+use crate::helpers::geometry::Point2;
+use crate::helpers::grid::Grid;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Only this many differing cells are listed when grids don't match
+const MAX_LISTED_CELLS: usize = 10;
+
+/// Group equal values, counting how many times each appears. Only needs `Eq`, so this is quadratic, but test data is
+/// small.
+fn count_occurrences<T: Eq>(values: impl IntoIterator<Item = T>) -> Vec<(T, usize)> {
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(existing, _)| existing == &value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+
+    counts
+}
+
+/// The differences between two multisets, with how many more times each element appears in one than the other
+#[derive(Debug, Eq, PartialEq)]
+struct MultisetDiff<T> {
+    missing: Vec<(T, usize)>,
+    unexpected: Vec<(T, usize)>,
+}
+
+impl<T: Eq> MultisetDiff<T> {
+    fn new(actual: impl IntoIterator<Item = T>, expected: impl IntoIterator<Item = T>) -> Self {
+        let mut actual = count_occurrences(actual);
+        let mut missing = Vec::new();
+
+        for (value, expected_count) in count_occurrences(expected) {
+            let actual_count = match actual.iter().position(|(existing, _)| existing == &value) {
+                Some(index) => actual.remove(index).1,
+                None => 0,
+            };
+
+            if expected_count > actual_count {
+                missing.push((value, expected_count - actual_count));
+            } else if actual_count > expected_count {
+                actual.push((value, actual_count - expected_count));
+            }
+        }
+
+        MultisetDiff {
+            missing,
+            unexpected: actual,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+impl<T: Debug> Display for MultisetDiff<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (heading, values) in [("missing", &self.missing), ("unexpected", &self.unexpected)] {
+            if values.is_empty() {
+                continue;
+            }
+            writeln!(f, "  {}:", heading)?;
+            for (value, count) in values {
+                writeln!(f, "    {} × {:?}", count, value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Assert that two collections have the same elements, the same number of times each, in any order
+#[track_caller]
+pub(crate) fn assert_contains_in_any_order<T>(
+    actual: impl IntoIterator<Item = T>,
+    expected: impl IntoIterator<Item = T>,
+) where
+    T: Debug + Eq,
+{
+    let diff = MultisetDiff::new(actual, expected);
+
+    assert!(diff.is_empty(), "The collections differ:\n{}", diff);
+}
+
+/// Assert that two grids have the same dimensions and cells. On failure both grids are shown, along with the first few
+/// cells that differ.
+#[track_caller]
+pub(crate) fn assert_grid_eq<T>(actual: &Grid<T>, expected: &Grid<T>)
+where
+    T: Display + PartialEq,
+{
+    assert!(
+        actual.width() == expected.width() && actual.height() == expected.height(),
+        "Expected a {}x{} grid, found {}x{}:\n{}",
+        expected.width(),
+        expected.height(),
+        actual.width(),
+        actual.height(),
+        actual
+    );
+
+    let differences: Vec<(Point2<usize>, &T, &T)> = expected
+        .iter()
+        .map(|(position, expected_cell)| (position, expected_cell, &actual[position]))
+        .filter(|(_, expected_cell, actual_cell)| expected_cell != actual_cell)
+        .collect();
+
+    if differences.is_empty() {
+        return;
+    }
+
+    let listed: String = differences
+        .iter()
+        .take(MAX_LISTED_CELLS)
+        .map(|(position, expected_cell, actual_cell)| {
+            format!(
+                "    ({}, {}): expected {}, found {}\n",
+                position.x, position.y, expected_cell, actual_cell
+            )
+        })
+        .collect();
+
+    panic!(
+        "The grids differ in {} cells:\n{}expected:\n{}\nactual:\n{}",
+        differences.len(),
+        listed,
+        expected,
+        actual
+    );
+}
+
+/// Assert that two floats are within `tolerance` of each other
+#[track_caller]
+pub(crate) fn assert_approx_eq(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "Expected {} ± {}, found {}",
+        expected,
+        tolerance,
+        actual
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::test::*;
+
+    #[test]
+    fn can_diff_multisets() {
+        let diff = MultisetDiff::new([1, 1, 2, 4], [1, 2, 2, 3]);
+
+        assert_eq!(
+            diff,
+            MultisetDiff {
+                missing: vec![(2, 1), (3, 1)],
+                unexpected: vec![(4, 1), (1, 1)],
+            }
+        );
+        assert_eq!(
+            diff.to_string(),
+            "  missing:\n    1 × 2\n    1 × 3\n  unexpected:\n    1 × 4\n    1 × 1\n"
+        );
+
+        assert!(MultisetDiff::new([3, 1, 2, 1], [1, 1, 2, 3]).is_empty());
+    }
+
+    #[test]
+    fn can_assert_in_any_order() {
+        assert_contains_in_any_order(vec!["b", "a", "b"], vec!["b", "b", "a"]);
+        assert_contains_in_any_order(Vec::<u8>::new(), vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing:\n    1 × 2")]
+    fn cannot_assert_different_counts_in_any_order() {
+        assert_contains_in_any_order([1, 1, 2], [1, 2, 2]);
+    }
+
+    #[test]
+    fn can_assert_grids() {
+        assert_grid_eq(&Grid::parse("ab\ncd", |c| c), &Grid::parse("ab\ncd", |c| c));
+    }
+
+    #[test]
+    #[should_panic(expected = "The grids differ in 1 cells:\n    (1, 1): expected d, found x")]
+    fn cannot_assert_different_grids() {
+        assert_grid_eq(&Grid::parse("ab\ncx", |c| c), &Grid::parse("ab\ncd", |c| c));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a 2x2 grid, found 3x1")]
+    fn cannot_assert_different_sized_grids() {
+        assert_grid_eq(&Grid::parse("abc", |c| c), &Grid::parse("ab\ncd", |c| c));
+    }
+
+    #[test]
+    fn can_assert_floats() {
+        assert_approx_eq(0.1 + 0.2, 0.3, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 0.3 ± 0.01, found 0.35")]
+    fn cannot_assert_distant_floats() {
+        assert_approx_eq(0.35, 0.3, 0.01);
+    }
+}
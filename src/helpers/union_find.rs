@@ -0,0 +1,149 @@
+//! A disjoint-set forest, for puzzles that merge elements into groups and ask how the groups end up.
+//!
+//! Elements are identified by their index, `0..len`, and each starts in its own component.
+//!
+//! - [`UnionFind::union`] merges the components containing two elements, attaching the smaller tree to the larger
+//! - [`UnionFind::find`] returns the root identifying an element's component, compressing the path as it goes
+//! - [`UnionFind::component_count`] and [`UnionFind::component_size`] are tracked as components merge, so are
+//!   available without rescanning the elements
+
+/// Disjoint sets of the elements `0..len`, with path compression and union by size
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    /// The parent of each element in its tree. Roots are their own parent.
+    parents: Vec<usize>,
+    /// The size of the component, only kept up to date for roots
+    sizes: Vec<usize>,
+    component_count: usize,
+}
+
+impl UnionFind {
+    /// Create `len` elements, each in its own component
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            component_count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The root of the component containing `element`. Each element visited is pointed directly at the root, so later
+    /// lookups are quicker.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Merge the components containing `a` and `b`. Returns `false` if they were already in the same component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        let (larger, smaller) = if self.sizes[root_a] >= self.sizes[root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+        self.component_count -= 1;
+
+        true
+    }
+
+    #[allow(dead_code)]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of separate components
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// The number of elements in the component containing `element`
+    #[allow(dead_code)]
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// The size of every component, in the order of their roots
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&element| self.parents[element] == element)
+            .map(|root| self.sizes[root])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::union_find::*;
+
+    #[test]
+    fn can_merge_components() {
+        let mut components = UnionFind::new(6);
+        assert_eq!(components.component_count(), 6);
+        assert!(!components.connected(0, 1));
+
+        assert!(components.union(0, 1));
+        assert!(components.union(2, 3));
+        assert!(components.union(1, 3));
+        assert!(!components.union(0, 2));
+
+        assert!(components.connected(0, 3));
+        assert!(!components.connected(0, 4));
+        assert_eq!(components.component_count(), 3);
+        assert_eq!(components.component_size(2), 4);
+        assert_eq!(components.component_size(5), 1);
+
+        let mut sizes = components.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+
+    #[test]
+    fn can_compress_paths() {
+        let mut components = UnionFind::new(4);
+        components.union(0, 1);
+        components.union(2, 3);
+        components.union(0, 2);
+
+        // 3 was attached to 2, which was then attached to 0
+        assert_eq!(components.parents[3], 2);
+        let root = components.find(3);
+        assert_eq!(root, 0);
+        assert_eq!(components.parents[3], root);
+    }
+
+    #[test]
+    fn can_handle_no_elements() {
+        let components = UnionFind::new(0);
+
+        assert!(components.is_empty());
+        assert_eq!(components.component_count(), 0);
+        assert_eq!(components.component_sizes(), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::bootstrap_day::BootstrapError;
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    Io(std::io::Error),
+    Reqwest(reqwest::Error),
+    Json(serde_json::Error),
+    Client(BootstrapError),
+}
+
+impl From<std::io::Error> for LeaderboardError {
+    fn from(err: std::io::Error) -> Self {
+        LeaderboardError::Io(err)
+    }
+}
+
+impl From<reqwest::Error> for LeaderboardError {
+    fn from(err: reqwest::Error) -> Self {
+        LeaderboardError::Reqwest(err)
+    }
+}
+
+impl From<serde_json::Error> for LeaderboardError {
+    fn from(err: serde_json::Error) -> Self {
+        LeaderboardError::Json(err)
+    }
+}
+
+impl From<BootstrapError> for LeaderboardError {
+    fn from(err: BootstrapError) -> Self {
+        LeaderboardError::Client(err)
+    }
+}
+
+impl Display for LeaderboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeaderboardError::Io(e) => write!(f, "IO Error: {}", e),
+            LeaderboardError::Reqwest(e) => write!(f, "Reqwest Error: {}", e),
+            LeaderboardError::Json(e) => write!(f, "JSON Error: {}", e),
+            LeaderboardError::Client(e) => write!(f, "Client Error: {}", e),
+        }
+    }
+}
+
+impl Error for LeaderboardError {}
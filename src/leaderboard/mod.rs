@@ -0,0 +1,346 @@
+//! Fetches and renders a private leaderboard, so the team's progress can be checked from the terminal.
+//!
+//! - [`load_leaderboard_json`] returns the leaderboard JSON, either from the local cache in `res/`, or by fetching it
+//!   with the authenticated client from [`crate::bootstrap_day::build_client`] if the cache is older than
+//!   [`MIN_REFRESH_INTERVAL`]
+//! - [`Leaderboard`] and [`Member`] model the parts of the JSON API that are needed for rendering
+//! - [`render_leaderboard`] formats the members as a table with stars per day, followed by the completion times of
+//!   each part relative to the puzzle unlocking
+
+use crate::bootstrap_day::build_client;
+use itertools::Itertools;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+pub use leaderboard_error::LeaderboardError;
+
+mod leaderboard_error;
+
+/// The site asks that private leaderboards are not requested more often than every 15 minutes.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// 2025-12-01T05:00:00Z, i.e. midnight EST when the first puzzle unlocks. Used if the JSON doesn't provide `day1_ts`.
+const DEFAULT_DAY_1_TIMESTAMP: u64 = 1764565200;
+
+/// 2025 has 12 puzzles. Used if the JSON doesn't provide `num_days`.
+const DEFAULT_NUM_DAYS: u8 = 12;
+
+/// The subset of the private leaderboard API response that is used for rendering
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    #[serde(default)]
+    day1_ts: Option<u64>,
+    #[serde(default)]
+    num_days: Option<u8>,
+    members: HashMap<String, Member>,
+}
+
+/// A member of the leaderboard, `completion_day_level` is keyed by day, then by part
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u32,
+    #[serde(default)]
+    last_star_ts: u64,
+    completion_day_level: HashMap<u8, HashMap<u8, Star>>,
+}
+
+/// When a member earned a star
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: u64,
+}
+
+impl Leaderboard {
+    /// The Unix timestamp the puzzle for a given day unlocked
+    fn unlock_timestamp(&self, day: u8) -> u64 {
+        self.day1_ts.unwrap_or(DEFAULT_DAY_1_TIMESTAMP) + (day as u64 - 1) * 24 * 60 * 60
+    }
+
+    fn num_days(&self) -> u8 {
+        self.num_days.unwrap_or(DEFAULT_NUM_DAYS)
+    }
+
+    /// Members ranked by score, ties are broken by stars, then by who got their last star first.
+    fn ranked_members(&self) -> Vec<&Member> {
+        self.members
+            .values()
+            .sorted_by_key(|member| {
+                (
+                    std::cmp::Reverse(member.local_score),
+                    std::cmp::Reverse(member.stars),
+                    member.last_star_ts,
+                    member.id,
+                )
+            })
+            .collect()
+    }
+
+    /// How long after the puzzle unlocked did the member complete the given part
+    fn completion_time(&self, member: &Member, day: u8, part: u8) -> Option<Duration> {
+        member
+            .completion_day_level
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+            .map(|star| {
+                Duration::from_secs(star.get_star_ts.saturating_sub(self.unlock_timestamp(day)))
+            })
+    }
+}
+
+impl Member {
+    /// Members can choose to be anonymous, in which case the site shows their id
+    fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// `*` if both parts are complete, `+` if only part one, `.` otherwise
+    fn star_glyph(&self, day: u8) -> char {
+        match self.completion_day_level.get(&day).map(|parts| parts.len()) {
+            Some(2) => '*',
+            Some(1) => '+',
+            _ => '.',
+        }
+    }
+}
+
+/// The entry point for the `leaderboard <id>` command. The id is numeric, which also keeps it safe to use in the
+/// cache's file name.
+pub fn show_leaderboard(id: u64) -> Result<(), LeaderboardError> {
+    let json = load_leaderboard_json(id)?;
+    let leaderboard: Leaderboard = serde_json::from_str(&json)?;
+
+    print!("{}", render_leaderboard(&leaderboard));
+
+    Ok(())
+}
+
+/// Has the cached file been modified recently enough that the API shouldn't be requested again
+fn is_cache_fresh(modified: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(modified)
+        .map(|age| age < MIN_REFRESH_INTERVAL)
+        .unwrap_or(true)
+}
+
+/// Use the cached copy of the leaderboard if it is fresh, otherwise fetch a new copy and update the cache.
+fn load_leaderboard_json(id: u64) -> Result<String, LeaderboardError> {
+    let cache_filename = format!("res/leaderboard-{}.json", id);
+    let cache_path = Path::new(&cache_filename);
+
+    if let Ok(modified) = fs::metadata(cache_path).and_then(|meta| meta.modified())
+        && is_cache_fresh(modified, SystemTime::now())
+    {
+        return Ok(fs::read_to_string(cache_path)?);
+    }
+
+    let client = build_client()?;
+    let target = format!(
+        "https://adventofcode.com/2025/leaderboard/private/view/{}.json",
+        id
+    );
+    let json = client.get(target).send()?.error_for_status()?.text()?;
+
+    // Check it parses before caching, an expired session returns a HTML login page
+    serde_json::from_str::<Leaderboard>(&json)?;
+    fs::create_dir_all("res")?;
+    fs::write(cache_path, &json)?;
+
+    Ok(json)
+}
+
+/// Format a duration as `HH:MM:SS`, or `>24h` as the site does for late solves
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 24 * 60 * 60 {
+        return ">24h".to_string();
+    }
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// Render the leaderboard as a table of members with their stars per day, followed by a table of completion times
+/// for each day that has been started by at least one member.
+fn render_leaderboard(leaderboard: &Leaderboard) -> String {
+    let members = leaderboard.ranked_members();
+    let name_width = members
+        .iter()
+        .map(|member| member.display_name().len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let days = 1..=leaderboard.num_days();
+
+    let mut output = String::new();
+
+    let day_headers = days.clone().map(|day| format!("{:>2}", day)).join(" ");
+    writeln!(
+        output,
+        "Rank Score Stars  {:<name_width$}  {}",
+        "Name", day_headers
+    )
+    .unwrap();
+
+    for (rank, member) in members.iter().enumerate() {
+        let glyphs = days
+            .clone()
+            .map(|day| format!("{:>2}", member.star_glyph(day)))
+            .join(" ");
+        writeln!(
+            output,
+            "{:>4} {:>5} {:>5}  {:<name_width$}  {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name(),
+            glyphs
+        )
+        .unwrap();
+    }
+
+    writeln!(output, "\n* both parts, + part one only, . not started").unwrap();
+
+    for day in days {
+        let solvers: Vec<&Member> = members
+            .iter()
+            .filter(|member| member.completion_day_level.contains_key(&day))
+            .sorted_by_key(|member| {
+                (
+                    leaderboard.completion_time(member, day, 2).is_none(),
+                    leaderboard.completion_time(member, day, 2),
+                    leaderboard.completion_time(member, day, 1),
+                )
+            })
+            .cloned()
+            .collect();
+
+        if solvers.is_empty() {
+            continue;
+        }
+
+        writeln!(
+            output,
+            "\nDay {:>2}  {:<name_width$}  Part 1    Part 2",
+            day, ""
+        )
+        .unwrap();
+        for member in solvers {
+            let [part_1, part_2] = [1, 2].map(|part| {
+                leaderboard
+                    .completion_time(member, day, part)
+                    .map(format_duration)
+                    .unwrap_or("-".to_string())
+            });
+            writeln!(
+                output,
+                "        {:<name_width$}  {:<8}  {}",
+                member.display_name(),
+                part_1,
+                part_2
+            )
+            .unwrap();
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leaderboard::*;
+
+    fn sample_leaderboard() -> Leaderboard {
+        serde_json::from_str(include_str!("fixtures/leaderboard.json")).unwrap()
+    }
+
+    #[test]
+    fn can_parse_leaderboard_json() {
+        let leaderboard = sample_leaderboard();
+
+        assert_eq!(leaderboard.members.len(), 4);
+        assert_eq!(leaderboard.num_days(), 12);
+
+        let names: Vec<String> = leaderboard
+            .ranked_members()
+            .iter()
+            .map(|member| member.display_name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "Elf Engineer",
+                "Jeff Horton",
+                "(anonymous user #1003)",
+                "Lurker"
+            ]
+        );
+    }
+
+    #[test]
+    fn can_summarise_member_progress() {
+        let leaderboard = sample_leaderboard();
+        let jeff = &leaderboard.members["1001"];
+
+        assert_eq!(jeff.star_glyph(1), '*');
+        assert_eq!(jeff.star_glyph(3), '+');
+        assert_eq!(jeff.star_glyph(4), '.');
+
+        assert_eq!(
+            leaderboard.completion_time(jeff, 1, 1),
+            Some(Duration::from_secs(754))
+        );
+        assert_eq!(
+            leaderboard.completion_time(jeff, 2, 2),
+            Some(Duration::from_secs(3000))
+        );
+        assert_eq!(leaderboard.completion_time(jeff, 3, 2), None);
+
+        assert_eq!(format_duration(Duration::from_secs(754)), "00:12:34");
+        assert_eq!(format_duration(Duration::from_secs(24 * 60 * 60)), ">24h");
+    }
+
+    #[test]
+    fn can_check_cache_freshness() {
+        let now = SystemTime::now();
+
+        assert!(is_cache_fresh(now - Duration::from_secs(60), now));
+        assert!(!is_cache_fresh(now - MIN_REFRESH_INTERVAL, now));
+        assert!(!is_cache_fresh(now - Duration::from_secs(60 * 60), now));
+    }
+
+    #[test]
+    fn can_render_leaderboard() {
+        let rendered = render_leaderboard(&sample_leaderboard());
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "Rank Score Stars  Name                     1  2  3  4  5  6  7  8  9 10 11 12"
+        );
+        assert_eq!(
+            lines[1],
+            "   1    28     4  Elf Engineer             *  *  .  .  .  .  .  .  .  .  .  ."
+        );
+        assert_eq!(
+            lines[2],
+            "   2    26     5  Jeff Horton              *  *  +  .  .  .  .  .  .  .  .  ."
+        );
+
+        assert!(rendered.contains("        Elf Engineer            00:05:00  00:13:20"));
+        assert!(rendered.contains("        (anonymous user #1003)  >24h      -"));
+        assert!(rendered.contains("\nDay  3"));
+        assert!(!rendered.contains("\nDay  4"));
+    }
+}
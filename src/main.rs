@@ -4,7 +4,15 @@ extern crate itertools;
 #[macro_use]
 extern crate text_io;
 mod bootstrap_day;
+mod generators;
 mod helpers;
+mod leaderboard;
+mod manifest;
+mod profiles;
+mod progress;
+mod registry;
+mod snippets;
+mod vault;
 
 mod day_1;
 mod day_2;
@@ -16,35 +24,162 @@ mod day_7;
 mod day_8;
 
 use bootstrap_day::bootstrap_day;
+use profiles::DEFAULT_PROFILE;
+use std::env;
 use std::io::{self, Write};
 use std::time::Instant;
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        [] => run_days(false),
+        ["--record-timings"] => run_days(true),
+        ["run", day, profile] => run_profile(parse_day(day), profile),
+        ["run", "2", profile, "--overlaps"] => {
+            day_2::run_with_radix(10, &profiles::input_path(2, profile), true)
+        }
+        ["run", "2", profile, "--explain"] => {
+            day_2::run_explain(10, &profiles::input_path(2, profile))
+        }
+        ["leaderboard", id] => match id.parse() {
+            Ok(id) => leaderboard::show_leaderboard(id).expect("Failed to show leaderboard"),
+            Err(_) => println!("Invalid leaderboard id: {}, expected a number", id),
+        },
+        ["manifest"] => manifest::write_manifest().expect("Failed to write manifest"),
+        ["progress"] => progress::show_progress(false),
+        ["progress", "--refresh"] => progress::show_progress(true),
+        ["check-snippets"] => {
+            if !snippets::check_snippets() {
+                std::process::exit(1)
+            }
+        }
+        ["vault", "keygen"] => vault::generate_key().expect("Failed to generate key"),
+        ["vault", "encrypt", day] => {
+            vault::encrypt_input(parse_day(day)).expect("Failed to encrypt input")
+        }
+        ["vault", "decrypt", day] => {
+            vault::decrypt_input(parse_day(day)).expect("Failed to decrypt input")
+        }
+        ["fetch-inputs", day] => {
+            profiles::fetch_profile_inputs(parse_day(day)).expect("Failed to fetch inputs")
+        }
+        ["check-profiles", day] => {
+            let solution = registry::find_solution(parse_day(day)).expect("Day is not solved");
+            if !profiles::check_profiles(&solution) {
+                std::process::exit(1)
+            }
+        }
+        ["dial-trace", rest @ ..] if rest.len() <= 1 => {
+            vault::ensure_input(1);
+            day_1::run_trace(
+                &profiles::input_path(1, DEFAULT_PROFILE),
+                rest.first().copied(),
+            )
+            .expect("Failed to trace the dial")
+        }
+        ["lock", path] => day_1::run_lock(path, false).expect("Failed to run the lock"),
+        ["lock", path, "--odometer"] => {
+            day_1::run_lock(path, true).expect("Failed to run the lock")
+        }
+        ["gift-shop", radix, path] => with_radix(Some(radix), |radix| {
+            day_2::run_with_radix(radix, path, false)
+        }),
+        ["gift-shop", radix, path, "--overlaps"] => with_radix(Some(radix), |radix| {
+            day_2::run_with_radix(radix, path, true)
+        }),
+        ["gift-shop", radix, path, "--explain"] => {
+            with_radix(Some(radix), |radix| day_2::run_explain(radix, path))
+        }
+        ["invalid-id", "--nth", n, rest @ ..] if rest.len() <= 1 => {
+            with_radix(rest.first().copied(), |radix| {
+                day_2::run_nth_invalid_id(n.parse().expect("N should be a number"), radix)
+            })
+        }
+        ["invalid-id", id, rest @ ..] if rest.len() <= 1 => {
+            with_radix(rest.first().copied(), |radix| {
+                day_2::run_invalid_id(id, radix)
+            })
+        }
+        ["generate", day, rest @ ..] if rest.len() <= 2 => {
+            let size = rest
+                .first()
+                .map(|size| size.parse().expect("Size should be a number"));
+            let seed = rest
+                .get(1)
+                .map_or(0, |seed| seed.parse().expect("Seed should be a number"));
+            generators::write_generated_input(parse_day(day), size, seed)
+                .expect("Failed to write generated input")
+        }
+        ["bench", day, rest @ ..] if rest.len() <= 1 => {
+            let solution = registry::find_solution(parse_day(day)).expect("Day is not solved");
+            let seed = rest
+                .first()
+                .map_or(0, |seed| seed.parse().expect("Seed should be a number"));
+            generators::run_benchmark(&solution, seed)
+        }
+        _ => println!("Unknown command: {}", args.join(" ")),
+    }
+}
+
+fn parse_day(day: &str) -> u8 {
+    day.parse().expect("Day should be a number")
+}
+
+/// Run a day 2 command with the radix argument, which defaults to decimal. A radix that isn't a number from 2 to 36 is
+/// reported as a usage error instead.
+fn with_radix(radix: Option<&str>, command: impl FnOnce(u32)) {
+    let parsed = radix.map_or(Some(10), |radix| {
+        radix.parse().ok().filter(|radix| (2..=36).contains(radix))
+    });
+
+    match parsed {
+        Some(radix) => command(radix),
+        None => println!(
+            "Invalid radix: {}, expected a number from 2 to 36",
+            radix.unwrap_or_default()
+        ),
+    }
+}
+
+/// Run a day's solutions with a profile's input instead of the [`DEFAULT_PROFILE`]
+fn run_profile(day: u8, profile: &str) {
+    match registry::find_solution(day) {
+        Some(solution) => (solution.run)(&profiles::input_path(day, profile)),
+        None => println!("Day {} is not solved", day),
+    }
+}
+
+/// Prompt for a day, then run that day's solutions, run all days, or bootstrap a new day as appropriate. Run times are
+/// only saved for the manifest when `record_timings` is set.
+fn run_days(record_timings: bool) {
     print!("Which day? (0 to run all): ");
     io::stdout().flush().unwrap();
 
     let day: u8 = read!();
-    let days: Vec<Box<dyn Fn() -> ()>> = vec![
-        Box::new(|| day_1::run()),
-        Box::new(|| day_2::run()),
-        Box::new(|| day_3::run()),
-        Box::new(|| day_4::run()),
-        Box::new(|| day_5::run()),
-        Box::new(|| day_6::run()),
-        Box::new(|| day_7::run()),
-        Box::new(|| day_8::run()),
-    ];
 
     let start = Instant::now();
-    match day.checked_sub(1).and_then(|idx| days.get(idx as usize)) {
-        Some(solution) => solution(),
-        None if day == 0 => days.iter().enumerate().for_each(|(i, solution)| {
+    match registry::find_solution(day) {
+        Some(solution) => {
+            vault::ensure_input(day);
+            (solution.run)(&profiles::input_path(day, DEFAULT_PROFILE));
+            if record_timings {
+                manifest::record_timing(day, start.elapsed());
+            }
+        }
+        None if day == 0 => registry::solutions().iter().for_each(|solution| {
             let start = Instant::now();
-            println!("==== Day {} ====", i + 1);
-            solution();
-            println!("-- took {:.2?}", start.elapsed());
+            println!("==== Day {} ====", solution.day);
+            vault::ensure_input(solution.day);
+            (solution.run)(&profiles::input_path(solution.day, DEFAULT_PROFILE));
+            let elapsed = start.elapsed();
+            if record_timings {
+                manifest::record_timing(solution.day, elapsed);
+            }
+            println!("-- took {:.2?}", elapsed);
         }),
-        None if day >= 1 && day <= 25 => bootstrap_day(day).expect("Failed to bootstrap day"),
+        None if (1..=25).contains(&day) => bootstrap_day(day).expect("Failed to bootstrap day"),
         None => println!("Invalid Day {}", day),
     }
 
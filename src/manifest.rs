@@ -0,0 +1,175 @@
+//! Exports metadata about each solution from the [`crate::registry`] as JSON, for the write-up site to consume.
+//!
+//! - [`record_timing`] is called by the runner, when asked to, to keep the most recent run time of each day in
+//!   `res/timings.json`
+//! - [`manifest_entry`] builds the [`ManifestEntry`] for a single day
+//! - [`build_manifest`] builds the entries for all days, which [`write_manifest`] writes to [`MANIFEST_PATH`]
+
+use crate::registry::{Solution, solutions};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::Duration;
+
+/// Written into the 11ty data directory so it is available to templates as `manifest`
+const MANIFEST_PATH: &str = "pubs/_data/manifest.json";
+
+const TIMINGS_PATH: &str = "res/timings.json";
+
+/// Recorded run times in milliseconds, keyed by day
+type Timings = BTreeMap<u8, f64>;
+
+/// The metadata the write-up site needs about a day
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ManifestEntry {
+    day: u8,
+    title: String,
+    puzzle_url: String,
+    source_path: String,
+    doc_path: String,
+    test_count: usize,
+    timing_ms: Option<f64>,
+}
+
+fn read_timings() -> Timings {
+    fs::read_to_string(TIMINGS_PATH)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Update the recorded run time for a day. This is only informational, so failures are reported but not fatal.
+pub fn record_timing(day: u8, duration: Duration) {
+    let mut timings = read_timings();
+    timings.insert(day, duration.as_secs_f64() * 1000.0);
+
+    let result = serde_json::to_string_pretty(&timings)
+        .map_err(std::io::Error::from)
+        .and_then(|json| fs::write(TIMINGS_PATH, json));
+
+    if let Err(err) = result {
+        println!("Failed to record timing for day {}: {}", day, err);
+    }
+}
+
+/// Counts the unit tests in a day's source file
+fn count_tests(source: &str) -> usize {
+    source
+        .lines()
+        .filter(|line| line.trim() == "#[test]")
+        .count()
+}
+
+/// Build the metadata for a day given the contents of its source file and recorded timing
+fn manifest_entry(solution: &Solution, source: &str, timing_ms: Option<f64>) -> ManifestEntry {
+    let day = solution.day;
+
+    ManifestEntry {
+        day,
+        title: solution.title.to_string(),
+        puzzle_url: format!("https://adventofcode.com/2025/day/{}", day),
+        source_path: format!("src/day_{}.rs", day),
+        doc_path: format!("advent_of_code_2025/day_{}/index.html", day),
+        test_count: count_tests(source),
+        timing_ms,
+    }
+}
+
+/// Build the metadata for every day in the registry from the current source files and recorded timings
+fn build_manifest() -> Result<Vec<ManifestEntry>, std::io::Error> {
+    let timings = read_timings();
+
+    solutions()
+        .iter()
+        .map(|solution| {
+            let source = fs::read_to_string(format!("src/day_{}.rs", solution.day))?;
+            Ok(manifest_entry(
+                solution,
+                &source,
+                timings.get(&solution.day).copied(),
+            ))
+        })
+        .collect()
+}
+
+/// The entry point for the `manifest` command
+pub fn write_manifest() -> Result<(), std::io::Error> {
+    let entries = build_manifest()?;
+
+    fs::write(
+        MANIFEST_PATH,
+        serde_json::to_string_pretty(&entries)? + "\n",
+    )?;
+    println!(
+        "Manifest for {} days written to {}",
+        entries.len(),
+        MANIFEST_PATH
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifest::*;
+
+    #[test]
+    fn can_count_tests() {
+        let source = "\
+fn solve() {}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn can_solve() {}
+
+    #[test]
+    fn can_solve_again() {}
+}";
+
+        assert_eq!(count_tests(source), 2);
+    }
+
+    #[test]
+    fn can_build_manifest_entry() {
+        let solution = Solution {
+            day: 3,
+            title: "Lobby",
+            run: |_| {},
+            solve: |_| (String::new(), String::new()),
+        };
+
+        assert_eq!(
+            manifest_entry(&solution, "#[test]\nfn test() {}", Some(1.5)),
+            ManifestEntry {
+                day: 3,
+                title: "Lobby".to_string(),
+                puzzle_url: "https://adventofcode.com/2025/day/3".to_string(),
+                source_path: "src/day_3.rs".to_string(),
+                doc_path: "advent_of_code_2025/day_3/index.html".to_string(),
+                test_count: 1,
+                timing_ms: Some(1.5),
+            }
+        );
+    }
+
+    #[test]
+    fn can_match_committed_manifest_test_counts() {
+        let committed: Vec<ManifestEntry> =
+            serde_json::from_str(&fs::read_to_string(MANIFEST_PATH).unwrap()).unwrap();
+        let test_counts = |entries: &[ManifestEntry]| -> Vec<(u8, usize)> {
+            entries
+                .iter()
+                .map(|entry| (entry.day, entry.test_count))
+                .collect()
+        };
+
+        // Timings depend on the machine, so only the test counts are expected to be kept up to date
+        assert_eq!(
+            test_counts(&committed),
+            test_counts(&build_manifest().unwrap()),
+            "{} is out of date, regenerate it with `cargo run -- manifest`",
+            MANIFEST_PATH
+        );
+    }
+}
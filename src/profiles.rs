@@ -0,0 +1,224 @@
+//! Supports several team members' puzzle inputs for each day, as a solution that works for one input can fail on
+//! another.
+//!
+//! - Each profile has a session cookie in `res/sessions/<profile>.txt`
+//! - [`fetch_profile_inputs`] downloads each profile's input for a day to `res/day-N/<profile>.txt`
+//! - Answers that have been accepted by the site are recorded one per line in `res/day-N/<profile>-answers.txt`
+//! - [`check_profiles`] solves a day for every profile's input, comparing the results with the recorded answers
+//! - The input the runner uses is the [`DEFAULT_PROFILE`], so it is stored and checked the same way as the others.
+//!   [`migrate_legacy_input`] moves an input from before profiles were added, `res/day-N-input.txt`, into place.
+
+use crate::bootstrap_day::{BootstrapError, build_client_for_session};
+use crate::registry::Solution;
+use itertools::Itertools;
+use std::fs;
+use std::io;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::Path;
+
+const SESSIONS_DIR: &str = "res/sessions";
+
+/// The profile for the 'real' input that the runner uses, downloaded when a day is bootstrapped
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// How a solution's answer compares to the recorded answer
+#[derive(Debug, Eq, PartialEq)]
+enum AnswerCheck {
+    Correct,
+    Incorrect { expected: String },
+    Unrecorded,
+}
+
+pub fn profile_dir(day: u8) -> String {
+    format!("res/day-{}", day)
+}
+
+pub fn input_path(day: u8, profile: &str) -> String {
+    format!("{}/{}.txt", profile_dir(day), profile)
+}
+
+pub fn answers_path(day: u8, profile: &str) -> String {
+    format!("{}/{}-answers.txt", profile_dir(day), profile)
+}
+
+/// Where the day's input was saved before there were profiles
+fn legacy_input_path(day: u8) -> String {
+    format!("res/day-{}-input.txt", day)
+}
+
+/// Move the day's input from [`legacy_input_path`] to the [`DEFAULT_PROFILE`]'s input, unless that already exists. The
+/// runner calls this before each day, so an existing checkout's inputs are moved the first time they're needed.
+pub fn migrate_legacy_input(day: u8) -> io::Result<()> {
+    let legacy_path = legacy_input_path(day);
+    let current_path = input_path(day, DEFAULT_PROFILE);
+    if !Path::new(&legacy_path).exists() || Path::new(&current_path).exists() {
+        return Ok(());
+    }
+
+    fs::create_dir_all(profile_dir(day))?;
+    fs::rename(&legacy_path, &current_path)?;
+    println!("Moved {} to {}", legacy_path, current_path);
+
+    Ok(())
+}
+
+/// The stems of the `.txt` files in a directory, sorted, excluding recorded answer files
+fn list_text_files(dir: &str) -> Vec<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .and_then(|name| name.strip_suffix(".txt"))
+                        .filter(|stem| !stem.ends_with("-answers"))
+                        .map(|stem| stem.to_string())
+                })
+                .sorted()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Answers are recorded one per line, part one then part two. Blank lines are ignored.
+pub fn read_recorded_answers(path: &str) -> Vec<String> {
+    fs::read_to_string(path)
+        .map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The entry point for the `fetch-inputs <day>` command. Downloads the input for every profile with a session cookie.
+pub fn fetch_profile_inputs(day: u8) -> Result<(), BootstrapError> {
+    let profiles = list_text_files(SESSIONS_DIR);
+    if profiles.is_empty() {
+        println!("No session cookies found in {}", SESSIONS_DIR);
+        return Ok(());
+    }
+
+    fs::create_dir_all(profile_dir(day))?;
+
+    for profile in profiles {
+        let client = build_client_for_session(&format!("{}/{}.txt", SESSIONS_DIR, profile))?;
+        let input = client
+            .get(format!("https://adventofcode.com/2025/day/{}/input", day))
+            .send()?
+            .error_for_status()?
+            .text()?;
+
+        let output_filename = input_path(day, &profile);
+        fs::write(&output_filename, input)?;
+        println!("Puzzle input for {} saved to {}", profile, output_filename);
+    }
+
+    Ok(())
+}
+
+fn check_answer(recorded: Option<&String>, actual: &str) -> AnswerCheck {
+    match recorded {
+        None => AnswerCheck::Unrecorded,
+        Some(expected) if expected == actual => AnswerCheck::Correct,
+        Some(expected) => AnswerCheck::Incorrect {
+            expected: expected.clone(),
+        },
+    }
+}
+
+fn describe_answer(actual: &str, check: &AnswerCheck) -> String {
+    match check {
+        AnswerCheck::Correct => format!("{} (correct)", actual),
+        AnswerCheck::Incorrect { expected } => format!("{} (expected {})", actual, expected),
+        AnswerCheck::Unrecorded => format!("{} (unrecorded)", actual),
+    }
+}
+
+/// The entry point for the `check-profiles <day>` command. Solves the day for each profile's input, reporting how the
+/// answers compare to those recorded. Panics in the solution are caught and reported, so that one bad input doesn't
+/// stop the others being checked. Returns whether all the recorded answers were matched.
+pub fn check_profiles(solution: &Solution) -> bool {
+    let day = solution.day;
+    let profiles = list_text_files(&profile_dir(day));
+    if profiles.is_empty() {
+        println!("No profile inputs found in {}", profile_dir(day));
+        return true;
+    }
+
+    let mut all_correct = true;
+
+    for profile in profiles {
+        let Ok(input) = fs::read_to_string(input_path(day, &profile)) else {
+            continue;
+        };
+        let recorded = read_recorded_answers(&answers_path(day, &profile));
+
+        println!("{}:", profile);
+        match catch_unwind(AssertUnwindSafe(|| (solution.solve)(&input))) {
+            Ok((part_one, part_two)) => {
+                for (part, actual) in [(1, part_one), (2, part_two)] {
+                    let check = check_answer(recorded.get(part - 1), &actual);
+                    if matches!(check, AnswerCheck::Incorrect { .. }) {
+                        all_correct = false;
+                    }
+                    println!("    Part {}: {}", part, describe_answer(&actual, &check));
+                }
+            }
+            Err(_) => {
+                all_correct = false;
+                println!("    The solution panicked");
+            }
+        }
+    }
+
+    all_correct
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::profiles::AnswerCheck::{Correct, Incorrect, Unrecorded};
+    use crate::profiles::*;
+
+    #[test]
+    fn can_check_answers() {
+        let recorded = ["3".to_string()];
+
+        assert_eq!(check_answer(recorded.first(), "3"), Correct);
+        assert_eq!(
+            check_answer(recorded.first(), "4"),
+            Incorrect {
+                expected: "3".to_string()
+            }
+        );
+        assert_eq!(check_answer(recorded.get(1), "6"), Unrecorded);
+    }
+
+    #[test]
+    fn can_describe_answers() {
+        assert_eq!(describe_answer("3", &Correct), "3 (correct)");
+        assert_eq!(
+            describe_answer(
+                "4",
+                &Incorrect {
+                    expected: "3".to_string()
+                }
+            ),
+            "4 (expected 3)"
+        );
+        assert_eq!(describe_answer("6", &Unrecorded), "6 (unrecorded)");
+    }
+
+    #[test]
+    fn can_solve_a_profile_input() {
+        let solution = crate::registry::find_solution(1).unwrap();
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82".to_string();
+
+        assert_eq!((solution.solve)(&input), ("3".to_string(), "6".to_string()));
+    }
+}
@@ -0,0 +1,274 @@
+//! Reports which days and parts are solved, by combining the event calendar from the site with what exists locally.
+//!
+//! - [`load_calendar`] fetches the event calendar page, caching it in `res/calendar.html` until it is older than
+//!   [`CALENDAR_REFRESH_INTERVAL`]
+//! - [`parse_calendar`] reads the star state of each unlocked day from the calendar's CSS classes
+//! - [`collect_progress`] combines the calendar with the [`crate::registry`], recorded answers, and write-ups
+//! - [`render_progress`] formats the result as a table
+
+use crate::bootstrap_day::{BootstrapError, build_client};
+use crate::profiles::{DEFAULT_PROFILE, answers_path, read_recorded_answers};
+use crate::registry::{Solution, solutions};
+use regex::Regex;
+use scraper::{Html, Selector};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// There are 12 puzzles in the 2025 event
+const NUM_DAYS: u8 = 12;
+
+const CALENDAR_CACHE: &str = "res/calendar.html";
+
+/// Stars change as puzzles are solved, so the calendar is fetched again after the same interval as the leaderboard
+const CALENDAR_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// The state of a day on the event calendar
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum CalendarDay {
+    /// The puzzle hasn't been released, or the calendar couldn't be loaded
+    Unknown,
+    /// The puzzle is unlocked, with the number of stars earned so far
+    Stars(u8),
+}
+
+/// Everything known about the progress of a single day
+#[derive(Debug, Eq, PartialEq)]
+struct DayProgress {
+    day: u8,
+    title: Option<&'static str>,
+    calendar: CalendarDay,
+    has_solution: bool,
+    recorded_answers: usize,
+    has_write_up: bool,
+}
+
+/// The entry point for the `progress` command. The cached calendar is used if it is fresh, unless `refresh` is set.
+pub fn show_progress(refresh: bool) {
+    let calendar = match load_calendar(refresh) {
+        Ok(calendar) => calendar,
+        Err(err) => {
+            println!(
+                "Unable to load the event calendar, star states will be unknown: {}",
+                err
+            );
+            HashMap::new()
+        }
+    };
+
+    print!(
+        "{}",
+        render_progress(&collect_progress(&calendar, &solutions()))
+    );
+}
+
+/// Use the cached calendar page if it is fresh and a refresh hasn't been requested, otherwise fetch and cache it
+fn load_calendar(refresh: bool) -> Result<HashMap<u8, CalendarDay>, BootstrapError> {
+    if !refresh
+        && let Ok(modified) = fs::metadata(CALENDAR_CACHE).and_then(|meta| meta.modified())
+        && is_cache_fresh(modified, SystemTime::now())
+    {
+        return Ok(parse_calendar(&fs::read_to_string(CALENDAR_CACHE)?));
+    }
+
+    let client = build_client()?;
+    let html = client
+        .get("https://adventofcode.com/2025")
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    let calendar = parse_fetched_calendar(&html)?;
+    fs::create_dir_all("res")?;
+    fs::write(CALENDAR_CACHE, &html)?;
+
+    Ok(calendar)
+}
+
+/// Has the cached calendar been modified recently enough that it shouldn't be requested again
+fn is_cache_fresh(modified: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(modified)
+        .map(|age| age < CALENDAR_REFRESH_INTERVAL)
+        .unwrap_or(true)
+}
+
+/// Check a fetched page has at least one unlocked day before it is cached, an expired session returns a HTML login
+/// page instead
+fn parse_fetched_calendar(html: &str) -> Result<HashMap<u8, CalendarDay>, BootstrapError> {
+    let calendar = parse_calendar(html);
+    if calendar.is_empty() {
+        return Err(BootstrapError::HtmlParse(
+            "The page has no calendar days, the session may have expired".to_string(),
+        ));
+    }
+
+    Ok(calendar)
+}
+
+/// Unlocked days are links with a `calendar-day{N}` class, with `calendar-complete` or `calendar-verycomplete` added
+/// once one or two stars are earned respectively. Locked days are not links, so are omitted.
+fn parse_calendar(html: &str) -> HashMap<u8, CalendarDay> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("pre.calendar a").unwrap();
+    let day_class = Regex::new(r"^calendar-day(?<day>\d+)$").unwrap();
+
+    document
+        .select(&selector)
+        .filter_map(|link| {
+            let classes: Vec<&str> = link.value().classes().collect();
+            let day = classes.iter().find_map(|class| {
+                day_class
+                    .captures(class)
+                    .and_then(|caps| caps["day"].parse::<u8>().ok())
+            })?;
+
+            let stars = if classes.contains(&"calendar-verycomplete") {
+                2
+            } else if classes.contains(&"calendar-complete") {
+                1
+            } else {
+                0
+            };
+
+            Some((day, CalendarDay::Stars(stars)))
+        })
+        .collect()
+}
+
+/// Combine the calendar with the local state of the project for each day of the event
+fn collect_progress(
+    calendar: &HashMap<u8, CalendarDay>,
+    solutions: &[Solution],
+) -> Vec<DayProgress> {
+    (1..=NUM_DAYS)
+        .map(|day| {
+            let solution = solutions.iter().find(|solution| solution.day == day);
+
+            DayProgress {
+                day,
+                title: solution.map(|solution| solution.title),
+                calendar: calendar.get(&day).copied().unwrap_or(CalendarDay::Unknown),
+                has_solution: solution.is_some(),
+                recorded_answers: read_recorded_answers(&answers_path(day, DEFAULT_PROFILE)).len(),
+                has_write_up: Path::new(&format!("pubs/blog/day_{}.md", day)).exists(),
+            }
+        })
+        .collect()
+}
+
+/// Render a table with a row per day
+fn render_progress(progress: &[DayProgress]) -> String {
+    let title_width = progress
+        .iter()
+        .filter_map(|day| day.title.map(str::len))
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let yes_no = |value: bool| if value { "yes" } else { "no" };
+
+    let mut output = String::new();
+    writeln!(
+        output,
+        "Day  {:<title_width$}  Stars  Solution  Answers  Write-up",
+        "Title"
+    )
+    .unwrap();
+
+    for day in progress {
+        let stars = match day.calendar {
+            CalendarDay::Unknown => "?".to_string(),
+            CalendarDay::Stars(0) => "-".to_string(),
+            CalendarDay::Stars(stars) => "*".repeat(stars as usize),
+        };
+
+        writeln!(
+            output,
+            "{:>3}  {:<title_width$}  {:<5}  {:<8}  {:<7}  {}",
+            day.day,
+            day.title.unwrap_or("-"),
+            stars,
+            yes_no(day.has_solution),
+            format!("{}/2", day.recorded_answers),
+            yes_no(day.has_write_up),
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::progress::CalendarDay::{Stars, Unknown};
+    use crate::progress::*;
+
+    #[test]
+    fn can_parse_calendar() {
+        let calendar = parse_calendar(include_str!("fixtures/calendar.html"));
+
+        assert_eq!(
+            calendar,
+            vec![(1, Stars(2)), (2, Stars(2)), (3, Stars(1)), (4, Stars(0))]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn can_reject_page_without_calendar() {
+        assert!(parse_fetched_calendar(include_str!("fixtures/calendar.html")).is_ok());
+        assert!(
+            parse_fetched_calendar("<html><body><p>Log in to continue</p></body></html>").is_err()
+        );
+    }
+
+    #[test]
+    fn can_check_cache_freshness() {
+        let now = SystemTime::now();
+
+        assert!(is_cache_fresh(now - Duration::from_secs(60), now));
+        assert!(!is_cache_fresh(now - CALENDAR_REFRESH_INTERVAL, now));
+    }
+
+    #[test]
+    fn can_render_progress() {
+        let progress = vec![
+            DayProgress {
+                day: 1,
+                title: Some("Secret Entrance"),
+                calendar: Stars(2),
+                has_solution: true,
+                recorded_answers: 2,
+                has_write_up: true,
+            },
+            DayProgress {
+                day: 2,
+                title: None,
+                calendar: Stars(0),
+                has_solution: false,
+                recorded_answers: 0,
+                has_write_up: false,
+            },
+            DayProgress {
+                day: 3,
+                title: None,
+                calendar: Unknown,
+                has_solution: false,
+                recorded_answers: 0,
+                has_write_up: false,
+            },
+        ];
+
+        assert_eq!(
+            render_progress(&progress),
+            "\
+Day  Title            Stars  Solution  Answers  Write-up
+  1  Secret Entrance  **     yes       2/2      yes
+  2  -                -      no        0/2      no
+  3  -                ?      no        0/2      no
+"
+        );
+    }
+}
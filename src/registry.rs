@@ -0,0 +1,93 @@
+//! The list of days that have a solution, so that the runner and tooling don't need to know about each day module
+//! individually. When a new day is bootstrapped, it needs adding to [`solutions`]. The title is read from the first
+//! line of the day's module doc, which the bootstrap writes, so it is only kept in one place.
+
+use crate::{day_1, day_2, day_3, day_4, day_5, day_6, day_7, day_8};
+
+/// Links a day's number and puzzle title to the entry points of its solution module
+pub struct Solution {
+    pub day: u8,
+    pub title: &'static str,
+    /// Runs the solution against the puzzle input at a path, printing the results
+    pub run: fn(&str),
+    /// Returns the answers for both parts for a given puzzle input
+    pub solve: fn(&str) -> (String, String),
+}
+
+impl Solution {
+    fn new(
+        day: u8,
+        title: &'static str,
+        run: fn(&str),
+        solve: fn(&str) -> (String, String),
+    ) -> Solution {
+        Solution {
+            day,
+            title,
+            run,
+            solve,
+        }
+    }
+}
+
+/// Register a day's module, taking the title from its source
+macro_rules! solution {
+    ($day:literal, $module:ident) => {
+        Solution::new(
+            $day,
+            doc_title(include_str!(concat!(stringify!($module), ".rs"))),
+            $module::run,
+            $module::solve,
+        )
+    };
+}
+
+/// All the solved days in order
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(1, day_1),
+        solution!(2, day_2),
+        solution!(3, day_3),
+        solution!(4, day_4),
+        solution!(5, day_5),
+        solution!(6, day_6),
+        solution!(7, day_7),
+        solution!(8, day_8),
+    ]
+}
+
+/// The puzzle title from the first line of a day's module doc, e.g.
+/// `//! This is my solution for [Advent of Code - Day 1: _Secret Entrance_](...)`
+fn doc_title(source: &'static str) -> &'static str {
+    source
+        .lines()
+        .next()
+        .and_then(|line| line.split_once(": _"))
+        .and_then(|(_, rest)| rest.split_once("_]"))
+        .map(|(title, _)| title)
+        .expect("A day's module doc should start with a link to the puzzle, including its title")
+}
+
+/// Find the solution for a given day if there is one
+pub fn find_solution(day: u8) -> Option<Solution> {
+    solutions().into_iter().find(|solution| solution.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::*;
+
+    #[test]
+    fn can_read_title_from_module_doc() {
+        assert_eq!(
+            doc_title(
+                "//! This is my solution for [Advent of Code - Day 1: _Secret Entrance_](https://adventofcode.com/2025/day/1)\n//!\n"
+            ),
+            "Secret Entrance"
+        );
+        assert_eq!(
+            find_solution(7).map(|solution| solution.title),
+            Some("Laboratories")
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! Checks that the code snippets in the write-ups still match the solutions they were copied from.
+//!
+//! The posts step through how a solution developed, so an item can appear several times. Only the final snippet of
+//! each item is expected to match the current source.
+//!
+//! - [`extract_rust_blocks`] pulls the fenced Rust code blocks out of a post
+//! - [`collect_items`] parses Rust code and indexes the items by name, with methods named `Type::method`
+//! - [`normalise`] renders an item's tokens without doc comments, so formatting and documentation changes are ignored
+//! - [`check_post`] compares the final snippet of each item in a post against the source
+
+use crate::registry::solutions;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::fs;
+use syn::{ImplItem, Item, Type};
+
+/// The outcome of checking a post against its source file
+#[derive(Debug, Default, Eq, PartialEq)]
+struct PostReport {
+    /// Items that match the source
+    matching: Vec<String>,
+    /// Items that are present in the source, but have a different implementation
+    drifted: Vec<String>,
+    /// Items that can't be found in the source, e.g. they've been renamed, or were only an intermediate step
+    missing: Vec<String>,
+    /// Code blocks that couldn't be parsed as Rust items
+    unparsable_blocks: usize,
+}
+
+/// The entry point for the `check-snippets` command. Returns whether all posts' snippets match their source.
+pub fn check_snippets() -> bool {
+    let mut all_match = true;
+
+    for solution in solutions() {
+        let post_path = format!("pubs/blog/day_{}.md", solution.day);
+        let source_path = format!("src/day_{}.rs", solution.day);
+
+        let (Ok(post), Ok(source)) = (
+            fs::read_to_string(&post_path),
+            fs::read_to_string(&source_path),
+        ) else {
+            continue;
+        };
+
+        let report = match check_post(&post, &source) {
+            Ok(report) => report,
+            Err(err) => {
+                println!("{}: failed to parse {}: {}", post_path, source_path, err);
+                all_match = false;
+                continue;
+            }
+        };
+
+        if report.drifted.is_empty() {
+            println!("{}: {} snippets match", post_path, report.matching.len());
+        } else {
+            all_match = false;
+            println!(
+                "{}: {} snippets no longer match {}",
+                post_path,
+                report.drifted.len(),
+                source_path
+            );
+            for name in &report.drifted {
+                println!("    changed: {}", name);
+            }
+        }
+
+        for name in &report.missing {
+            println!("    not in source: {}", name);
+        }
+        if report.unparsable_blocks > 0 {
+            println!("    skipped {} unparsable blocks", report.unparsable_blocks);
+        }
+    }
+
+    all_match
+}
+
+/// Return the contents of each fenced code block marked as `rust`
+fn extract_rust_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        match current.as_mut() {
+            None if line.trim() == "```rust" => current = Some(Vec::new()),
+            None => {}
+            Some(block) if line.trim() == "```" => {
+                blocks.push(block.join("\n"));
+                current = None;
+            }
+            Some(block) => block.push(line),
+        }
+    }
+
+    blocks
+}
+
+/// The name used to identify a type in an impl block, e.g. `PrintingDepartment` in
+/// `impl From<&str> for PrintingDepartment`
+fn type_name(self_ty: &Type) -> String {
+    match self_ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        other => other.to_token_stream().to_string(),
+    }
+}
+
+/// Index the items in some Rust code by name, recursing into inline modules (e.g. `mod tests`) and splitting impl
+/// blocks into their methods. Later items with the same name replace earlier ones.
+fn collect_items(code: &str) -> syn::Result<Vec<(String, TokenStream)>> {
+    fn collect(items: &[Item], output: &mut Vec<(String, TokenStream)>) {
+        for item in items {
+            match item {
+                Item::Fn(item_fn) => {
+                    output.push((item_fn.sig.ident.to_string(), item.to_token_stream()))
+                }
+                Item::Struct(item_struct) => {
+                    output.push((item_struct.ident.to_string(), item.to_token_stream()))
+                }
+                Item::Enum(item_enum) => {
+                    output.push((item_enum.ident.to_string(), item.to_token_stream()))
+                }
+                Item::Type(item_type) => {
+                    output.push((item_type.ident.to_string(), item.to_token_stream()))
+                }
+                Item::Const(item_const) => {
+                    output.push((item_const.ident.to_string(), item.to_token_stream()))
+                }
+                Item::Impl(item_impl) => {
+                    let self_name = type_name(&item_impl.self_ty);
+                    for impl_item in &item_impl.items {
+                        if let ImplItem::Fn(method) = impl_item {
+                            output.push((
+                                format!("{}::{}", self_name, method.sig.ident),
+                                method.to_token_stream(),
+                            ))
+                        }
+                    }
+                }
+                Item::Mod(item_mod) => {
+                    if let Some((_, content)) = &item_mod.content {
+                        collect(content, output)
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let file = syn::parse_file(code)?;
+    let mut output = Vec::new();
+    collect(&file.items, &mut output);
+
+    Ok(output)
+}
+
+/// Render tokens as a string, skipping `#[doc = "..."]` attributes (i.e. doc comments) at any depth
+fn normalise(tokens: TokenStream) -> String {
+    fn is_doc_attribute(tree: Option<&TokenTree>) -> bool {
+        match tree {
+            Some(TokenTree::Group(group)) => matches!(
+                group.stream().into_iter().next(),
+                Some(TokenTree::Ident(ident)) if ident == "doc"
+            ),
+            _ => false,
+        }
+    }
+
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut output = Vec::new();
+    let mut index = 0;
+
+    while index < trees.len() {
+        match &trees[index] {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                let bang =
+                    matches!(trees.get(index + 1), Some(TokenTree::Punct(p)) if p.as_char() == '!');
+                let attribute_index = if bang { index + 2 } else { index + 1 };
+
+                if is_doc_attribute(trees.get(attribute_index)) {
+                    index = attribute_index + 1;
+                    continue;
+                }
+                output.push(punct.to_string());
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    proc_macro2::Delimiter::Parenthesis => ("(", ")"),
+                    proc_macro2::Delimiter::Brace => ("{", "}"),
+                    proc_macro2::Delimiter::Bracket => ("[", "]"),
+                    proc_macro2::Delimiter::None => ("", ""),
+                };
+                output.push(format!("{}{}{}", open, normalise(group.stream()), close));
+            }
+            other => output.push(other.to_string()),
+        }
+        index += 1;
+    }
+
+    output.join(" ")
+}
+
+/// Compare the final snippet of each item in the post against the same item in the source. Snippets of methods are
+/// sometimes shown without their impl block, so a free function that isn't found in the source is also looked up
+/// as a method, if there is only one method with that name.
+fn check_post(post: &str, source: &str) -> syn::Result<PostReport> {
+    let source_items: HashMap<String, String> = collect_items(source)?
+        .into_iter()
+        .map(|(name, tokens)| (name, normalise(tokens)))
+        .collect();
+
+    let mut report = PostReport::default();
+    let mut snippets: Vec<(String, String)> = Vec::new();
+
+    for block in extract_rust_blocks(post) {
+        match collect_items(&block) {
+            Ok(items) => {
+                for (name, tokens) in items {
+                    snippets.retain(|(existing, _)| existing != &name);
+                    snippets.push((name, normalise(tokens)));
+                }
+            }
+            Err(_) => report.unparsable_blocks += 1,
+        }
+    }
+
+    for (name, snippet) in snippets {
+        let current = source_items.get(&name).or_else(|| {
+            let suffix = format!("::{}", name);
+            let mut methods = source_items
+                .iter()
+                .filter(|(key, _)| key.ends_with(&suffix));
+            match (methods.next(), methods.next()) {
+                (Some((_, tokens)), None) => Some(tokens),
+                _ => None,
+            }
+        });
+
+        match current {
+            Some(current) if current == &snippet => report.matching.push(name),
+            Some(_) => report.drifted.push(name),
+            None => report.missing.push(name),
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::snippets::*;
+
+    fn sample_source() -> String {
+        "\
+/// Documented
+fn solve(input: &str) -> usize {
+    input.len()
+}
+
+struct Grid {
+    width: usize,
+}
+
+impl Grid {
+    /// Also documented
+    fn area(&self) -> usize {
+        self.width * self.width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn can_solve() {
+        assert_eq!(solve(\"abc\"), 3);
+    }
+}"
+        .to_string()
+    }
+
+    #[test]
+    fn can_extract_rust_blocks() {
+        let post = "\
+Intro
+
+```rust
+fn a() {}
+```
+
+```shell
+cargo run
+```
+
+```rust
+fn b() {}
+```
+";
+
+        assert_eq!(extract_rust_blocks(post), vec!["fn a() {}", "fn b() {}"]);
+    }
+
+    #[test]
+    fn can_collect_items() {
+        let names: Vec<String> = collect_items(&sample_source())
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        assert_eq!(names, vec!["solve", "Grid", "Grid::area", "can_solve"]);
+    }
+
+    #[test]
+    fn can_ignore_docs_and_formatting() {
+        let documented: syn::ItemFn =
+            syn::parse_str("/// Docs\n#[test]\nfn a() { b(1,2) }").unwrap();
+        let formatted: syn::ItemFn = syn::parse_str("#[test] fn a() {\n    b(1, 2)\n}").unwrap();
+
+        assert_eq!(
+            normalise(documented.to_token_stream()),
+            normalise(formatted.to_token_stream())
+        );
+    }
+
+    #[test]
+    fn can_check_post() {
+        let post = "\
+```rust
+fn solve(input: &str) -> usize {
+    0
+}
+```
+
+The final version:
+
+```rust
+fn solve(input: &str) -> usize {
+    input.len()
+}
+
+fn area(&self) -> usize {
+    self.width + self.width
+}
+
+fn renamed() {}
+```
+
+```rust
+#[test]
+fn can_solve() {
+    assert_eq!(solve(\"abc\"), 3);
+}
+```
+
+```rust
+impl Grid {
+```
+";
+
+        assert_eq!(
+            check_post(post, &sample_source()).unwrap(),
+            PostReport {
+                matching: vec!["solve".to_string(), "can_solve".to_string()],
+                drifted: vec!["area".to_string()],
+                missing: vec!["renamed".to_string()],
+                unparsable_blocks: 1,
+            }
+        )
+    }
+
+    #[test]
+    fn can_match_every_post_to_its_source() {
+        assert!(
+            check_snippets(),
+            "Snippets in the posts no longer match the source, see the report above"
+        );
+    }
+}
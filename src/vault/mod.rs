@@ -0,0 +1,206 @@
+//! Stores encrypted copies of the puzzle inputs in the repository, so they can be shared with the team without
+//! publishing them.
+//!
+//! - [`generate_key`] creates a random key in `res/vault.key`. This stays out of the repository, and is shared with
+//!   the team separately.
+//! - [`encrypt_input`] encrypts `res/day-N/default.txt` into `vault/day-N-input.txt.enc`, which can be committed
+//! - [`decrypt_input`] reverses that, and [`ensure_input`] does so automatically for the runner if the plaintext
+//!   input is missing
+//!
+//! Files are encrypted with XChaCha20-Poly1305, and stored as the random 24-byte nonce followed by the ciphertext.
+
+use crate::profiles::{self, DEFAULT_PROFILE};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use itertools::Itertools;
+use std::fs;
+use std::path::Path;
+
+pub use vault_error::VaultError;
+
+mod vault_error;
+
+const KEY_PATH: &str = "res/vault.key";
+
+/// The length of an XChaCha20 nonce in bytes
+const NONCE_LENGTH: usize = 24;
+
+fn input_path(day: u8) -> String {
+    profiles::input_path(day, DEFAULT_PROFILE)
+}
+
+fn vault_path(day: u8) -> String {
+    format!("vault/day-{}-input.txt.enc", day)
+}
+
+/// Keys are stored as 64 hex digits
+fn encode_key(key: &Key) -> String {
+    key.iter().map(|byte| format!("{:02x}", byte)).join("")
+}
+
+fn parse_key(hex: &str) -> Result<Key, VaultError> {
+    let hex = hex.trim();
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(VaultError::Key(
+            "the key should be 64 hex digits".to_string(),
+        ));
+    }
+
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|err| VaultError::Key(err.to_string()))?;
+
+    Ok(*Key::from_slice(&bytes))
+}
+
+fn read_key() -> Result<Key, VaultError> {
+    let hex = fs::read_to_string(KEY_PATH)
+        .map_err(|err| VaultError::Key(format!("unable to read {}: {}", KEY_PATH, err)))?;
+
+    parse_key(&hex)
+}
+
+fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let cipher = XChaCha20Poly1305::new(key);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .expect("Encrypting to a Vec should not fail");
+
+    nonce.into_iter().chain(ciphertext).collect()
+}
+
+/// Fails if the data is truncated, was encrypted with a different key, or has been modified
+fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, VaultError> {
+    if data.len() < NONCE_LENGTH {
+        return Err(VaultError::Decryption(
+            "the file is too short to contain a nonce".to_string(),
+        ));
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LENGTH);
+    XChaCha20Poly1305::new(key)
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| {
+            VaultError::Decryption(
+                "the key doesn't match, or the file has been modified".to_string(),
+            )
+        })
+}
+
+/// The entry point for the `vault keygen` command. Won't replace an existing key, as that would make any files
+/// encrypted with it unreadable.
+pub fn generate_key() -> Result<(), VaultError> {
+    if Path::new(KEY_PATH).exists() {
+        return Err(VaultError::Key(format!(
+            "{} already exists, remove it first to replace it",
+            KEY_PATH
+        )));
+    }
+
+    let key = XChaCha20Poly1305::generate_key(&mut OsRng);
+    fs::write(KEY_PATH, encode_key(&key) + "\n")?;
+    println!(
+        "Key written to {}, share it with the team privately",
+        KEY_PATH
+    );
+
+    Ok(())
+}
+
+/// The entry point for the `vault encrypt <day>` command
+pub fn encrypt_input(day: u8) -> Result<(), VaultError> {
+    let key = read_key()?;
+    let plaintext = fs::read(input_path(day))?;
+
+    fs::create_dir_all("vault")?;
+    fs::write(vault_path(day), encrypt(&key, &plaintext))?;
+    println!("Encrypted {} to {}", input_path(day), vault_path(day));
+
+    Ok(())
+}
+
+/// The entry point for the `vault decrypt <day>` command
+pub fn decrypt_input(day: u8) -> Result<(), VaultError> {
+    let key = read_key()?;
+    let data = fs::read(vault_path(day))?;
+
+    fs::create_dir_all(profiles::profile_dir(day))?;
+    fs::write(input_path(day), decrypt(&key, &data)?)?;
+    println!("Decrypted {} to {}", vault_path(day), input_path(day));
+
+    Ok(())
+}
+
+/// Called by the runner before running a day. An input from before profiles is moved into place, then if the plaintext
+/// input is still missing, but there is an encrypted copy, decrypt it. Failures are reported, but left for the solution
+/// to fail when it can't read its input.
+pub fn ensure_input(day: u8) {
+    if let Err(err) = profiles::migrate_legacy_input(day) {
+        println!("Unable to move the input for day {}: {}", day, err);
+    }
+
+    if Path::new(&input_path(day)).exists() || !Path::new(&vault_path(day)).exists() {
+        return;
+    }
+
+    if let Err(err) = decrypt_input(day) {
+        println!("Unable to decrypt the input for day {}: {}", day, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vault::*;
+
+    fn sample_key() -> Key {
+        parse_key("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\n").unwrap()
+    }
+
+    #[test]
+    fn can_encode_and_parse_keys() {
+        let key = sample_key();
+
+        assert_eq!(key[0], 0);
+        assert_eq!(key[31], 31);
+        assert_eq!(parse_key(&encode_key(&key)).unwrap(), key);
+
+        assert!(parse_key("0001").is_err());
+        assert!(parse_key(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn can_encrypt_and_decrypt() {
+        let key = sample_key();
+        let plaintext = b"L68\nL30\nR48\n";
+
+        let encrypted = encrypt(&key, plaintext);
+        assert_eq!(encrypted.len(), NONCE_LENGTH + plaintext.len() + 16);
+        assert_ne!(
+            &encrypted[NONCE_LENGTH..NONCE_LENGTH + plaintext.len()],
+            plaintext
+        );
+
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), plaintext);
+
+        // A fresh nonce is used each time
+        assert_ne!(encrypt(&key, plaintext), encrypted);
+    }
+
+    #[test]
+    fn cannot_decrypt_with_wrong_key_or_modified_data() {
+        let key = sample_key();
+        let encrypted = encrypt(&key, b"1-3,10-11");
+
+        let other_key = XChaCha20Poly1305::generate_key(&mut OsRng);
+        assert!(decrypt(&other_key, &encrypted).is_err());
+
+        let mut modified = encrypted.clone();
+        modified[NONCE_LENGTH] ^= 1;
+        assert!(decrypt(&key, &modified).is_err());
+
+        assert!(decrypt(&key, &encrypted[..10]).is_err());
+    }
+}
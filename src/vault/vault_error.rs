@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum VaultError {
+    Io(std::io::Error),
+    Key(String),
+    Decryption(String),
+}
+
+impl From<std::io::Error> for VaultError {
+    fn from(err: std::io::Error) -> Self {
+        VaultError::Io(err)
+    }
+}
+
+impl Display for VaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VaultError::Io(e) => write!(f, "IO Error: {}", e),
+            VaultError::Key(e) => write!(f, "Key Error: {}", e),
+            VaultError::Decryption(e) => write!(f, "Decryption Error: {}", e),
+        }
+    }
+}
+
+impl Error for VaultError {}